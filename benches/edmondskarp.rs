@@ -1,5 +1,9 @@
 use criterion::{criterion_group, criterion_main, Criterion};
+use pathfinding::directed::dinic::Dinic;
 use pathfinding::directed::edmonds_karp::*;
+use pathfinding::directed::push_relabel::PushRelabel;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
 use std::collections::HashMap;
 
 /// Return a list of edges with their capacities.
@@ -61,5 +65,96 @@ fn wikipedia_example_sparse(c: &mut Criterion) {
     wikipedia_example::<SparseCapacity<_>>(c, "wikipedia_example_sparse");
 }
 
-criterion_group!(benches, wikipedia_example_dense, wikipedia_example_sparse,);
+fn wikipedia_example_dinic_dense(c: &mut Criterion) {
+    wikipedia_example::<Dinic<DenseCapacity<_>>>(c, "wikipedia_example_dinic_dense");
+}
+
+fn wikipedia_example_dinic_sparse(c: &mut Criterion) {
+    wikipedia_example::<Dinic<SparseCapacity<_>>>(c, "wikipedia_example_dinic_sparse");
+}
+
+/// Return a random dense capacity matrix.
+fn random_capacities(size: usize) -> Vec<i64> {
+    let mut rng = XorShiftRng::from_seed([42; 16]);
+    (0..size * size)
+        .map(|i| {
+            if i / size == i % size {
+                0
+            } else {
+                rng.gen_range(0..100)
+            }
+        })
+        .collect()
+}
+
+fn dense_random<EK: EdmondsKarp<i64>>(c: &mut Criterion, id: &str) {
+    let capacities = random_capacities(100);
+    c.bench_function(id, |b| {
+        b.iter(|| EK::from_vec(0, 99, capacities.clone()).augment())
+    });
+}
+
+fn dense_random_edmonds_karp(c: &mut Criterion) {
+    dense_random::<DenseCapacity<_>>(c, "dense_random_edmonds_karp");
+}
+
+fn dense_random_dinic(c: &mut Criterion) {
+    dense_random::<Dinic<DenseCapacity<_>>>(c, "dense_random_dinic");
+}
+
+fn dense_random_push_relabel(c: &mut Criterion) {
+    dense_random::<PushRelabel<DenseCapacity<_>>>(c, "dense_random_push_relabel");
+}
+
+/// Return the edges of a random bipartite network with unit capacities. The source
+/// is 0, the sink is 1, and every left vertex is connected to `degree` random
+/// right vertices.
+fn random_bipartite(side: usize, degree: usize) -> Vec<(usize, usize)> {
+    let mut rng = XorShiftRng::from_seed([43; 16]);
+    let mut edges = Vec::with_capacity(side * (degree + 2));
+    for i in 0..side {
+        edges.push((0, 2 + i));
+        edges.push((2 + side + i, 1));
+        for _ in 0..degree {
+            edges.push((2 + i, 2 + side + rng.gen_range(0..side)));
+        }
+    }
+    edges
+}
+
+fn bipartite_unit<EK: EdmondsKarp<i32>>(edges: &[(usize, usize)], size: usize) -> i32 {
+    let mut ek = EK::new(size, 0, 1);
+    for &(from, to) in edges {
+        ek.set_capacity(from, to, 1);
+    }
+    ek.omit_detailed_flows();
+    ek.augment().1
+}
+
+fn bipartite_unit_capacities(c: &mut Criterion) {
+    // About 100k edges, on which Edmonds-Karp needs several seconds.
+    let side = 5000;
+    let edges = random_bipartite(side, 20);
+    let mut group = c.benchmark_group("bipartite_unit_capacities");
+    group.sample_size(10);
+    group.bench_function("edmonds_karp_sparse", |b| {
+        b.iter(|| bipartite_unit::<SparseCapacity<_>>(&edges, 2 * side + 2))
+    });
+    group.bench_function("dinic_sparse", |b| {
+        b.iter(|| bipartite_unit::<Dinic<SparseCapacity<_>>>(&edges, 2 * side + 2))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    wikipedia_example_dense,
+    wikipedia_example_sparse,
+    wikipedia_example_dinic_dense,
+    wikipedia_example_dinic_sparse,
+    dense_random_edmonds_karp,
+    dense_random_dinic,
+    dense_random_push_relabel,
+    bipartite_unit_capacities,
+);
 criterion_main!(benches);
@@ -0,0 +1,191 @@
+//! Find a minimum spanning arborescence in a directed graph using the
+//! [Chu-Liu/Edmonds algorithm](https://en.wikipedia.org/wiki/Edmonds'_algorithm).
+
+use indexmap::IndexSet;
+use std::hash::Hash;
+use std::ops::Sub;
+
+// Information kept about a contraction step, used to expand the arborescence
+// found in the contracted graph.
+struct Level {
+    // Original index of the cheapest edge entering every vertex.
+    in_edges: Vec<usize>,
+    // Vertices of every contracted cycle, and cycle containing every vertex.
+    cycles: Vec<Vec<usize>>,
+    cycle_of: Vec<usize>,
+    // Original index of the edges entering a cycle, along with the vertex they enter.
+    entering: Vec<(usize, usize)>,
+}
+
+/// Minimum spanning arborescence for nodes with integer indices. The nodes must have
+/// consecutives indices between 0 and `number_of_nodes`-1.
+///
+/// # Panics
+///
+/// This function panics if a node is outside the range [0, `number_of_nodes`-1].
+pub fn arborescence_indices<C>(
+    number_of_nodes: usize,
+    root: usize,
+    edges: &[(usize, usize, C)],
+) -> Option<Vec<(usize, usize, C)>>
+where
+    C: Ord + Copy + Sub<Output = C>,
+{
+    assert!(root < number_of_nodes, "root is out of range");
+    let mut size = number_of_nodes;
+    let mut root = root;
+    // Edges of the current contracted graph, with their original index.
+    let mut current = edges
+        .iter()
+        .enumerate()
+        .filter(|&(_, &(a, b, _))| a != b && b != root)
+        .map(|(i, &(a, b, w))| (a, b, w, i))
+        .collect::<Vec<_>>();
+    let mut levels = Vec::new();
+    let in_edges = loop {
+        // Select the cheapest edge entering every vertex but the root.
+        let mut cheapest: Vec<Option<usize>> = vec![None; size];
+        for (i, &(_, b, w, _)) in current.iter().enumerate() {
+            if cheapest[b].map_or(true, |c| w < current[c].2) {
+                cheapest[b] = Some(i);
+            }
+        }
+        if (0..size).any(|n| n != root && cheapest[n].is_none()) {
+            return None;
+        }
+        // Look for cycles amongst the selected edges.
+        let mut cycle_of = vec![usize::MAX; size];
+        let mut cycles = Vec::new();
+        let mut visited = vec![usize::MAX; size];
+        for start in 0..size {
+            let mut n = start;
+            while n != root && visited[n] == usize::MAX {
+                visited[n] = start;
+                n = current[cheapest[n].unwrap()].0;
+            }
+            if n != root && visited[n] == start && cycle_of[n] == usize::MAX {
+                let mut cycle = Vec::new();
+                while cycle_of[n] == usize::MAX {
+                    cycle_of[n] = cycles.len();
+                    cycle.push(n);
+                    n = current[cheapest[n].unwrap()].0;
+                }
+                cycles.push(cycle);
+            }
+        }
+        let in_edges = cheapest
+            .iter()
+            .map(|c| c.map_or(usize::MAX, |c| current[c].3))
+            .collect::<Vec<_>>();
+        if cycles.is_empty() {
+            break in_edges;
+        }
+        // Contract every cycle into a single vertex, and reduce the weight of the
+        // edges entering a cycle by the weight of the cycle edge they would replace.
+        let mut components = cycle_of.clone();
+        let mut new_size = cycles.len();
+        for component in &mut components {
+            if *component == usize::MAX {
+                *component = new_size;
+                new_size += 1;
+            }
+        }
+        let mut entering = Vec::new();
+        let mut contracted = Vec::with_capacity(current.len());
+        for &(a, b, w, i) in &current {
+            if components[a] != components[b] {
+                if cycle_of[b] == usize::MAX {
+                    contracted.push((components[a], components[b], w, i));
+                } else {
+                    entering.push((i, b));
+                    let replaced = current[cheapest[b].unwrap()].2;
+                    contracted.push((components[a], components[b], w - replaced, i));
+                }
+            }
+        }
+        levels.push(Level {
+            in_edges,
+            cycles,
+            cycle_of,
+            entering,
+        });
+        current = contracted;
+        root = components[root];
+        size = new_size;
+    };
+    // Expand the contracted cycles: the edge entering a cycle replaces the cycle
+    // edge going to the same vertex.
+    let mut selected = in_edges
+        .into_iter()
+        .filter(|&i| i != usize::MAX)
+        .collect::<Vec<_>>();
+    let mut heads = vec![usize::MAX; edges.len()];
+    for level in levels.into_iter().rev() {
+        for &(i, n) in &level.entering {
+            heads[i] = n;
+        }
+        for index in 0..selected.len() {
+            let head = heads[selected[index]];
+            if head != usize::MAX {
+                let cycle = &level.cycles[level.cycle_of[head]];
+                selected.extend(
+                    cycle
+                        .iter()
+                        .filter(|&&n| n != head)
+                        .map(|&n| level.in_edges[n]),
+                );
+            }
+        }
+        for &(i, _) in &level.entering {
+            heads[i] = usize::MAX;
+        }
+    }
+    selected.sort_unstable();
+    Some(selected.into_iter().map(|i| edges[i]).collect())
+}
+
+/// Find a minimum spanning arborescence, that is a set of edges with the smallest
+/// total weight such that there is exactly one path from `root` to every other node.
+/// This is the directed counterpart of a minimum-spanning-tree, as returned by
+/// [`kruskal`](crate::undirected::kruskal::kruskal).
+///
+/// - `root` is the root of the arborescence.
+/// - `edges` is a collection of weighted directed edges, as `(from, to, weight)`
+///   triples. Weights may be negative.
+///
+/// The nodes of the graph are `root` and the ends of the edges. The function returns
+/// `None` if some nodes cannot be reached from `root`, or the edges of the arborescence
+/// in the order they appear in `edges`.
+///
+/// This function uses the Chu-Liu/Edmonds algorithm, and runs in O(|V| × |E|) time.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::arborescence;
+///
+/// let edges = [('r', 'a', 5), ('r', 'b', 1), ('a', 'b', 2), ('b', 'a', 3), ('a', 'c', 4), ('b', 'c', 9)];
+/// assert_eq!(
+///     arborescence(&'r', &edges),
+///     Some(vec![(&'r', &'b', 1), (&'b', &'a', 3), (&'a', &'c', 4)])
+/// );
+/// assert_eq!(arborescence(&'a', &edges), None);
+/// ```
+pub fn arborescence<'a, N, C>(root: &'a N, edges: &'a [(N, N, C)]) -> Option<Vec<(&'a N, &'a N, C)>>
+where
+    N: Hash + Eq,
+    C: Ord + Copy + Sub<Output = C>,
+{
+    let mut nodes = IndexSet::new();
+    nodes.insert(root);
+    let edges = edges
+        .iter()
+        .map(|(a, b, w)| (nodes.insert_full(a).0, nodes.insert_full(b).0, *w))
+        .collect::<Vec<_>>();
+    Some(
+        arborescence_indices(nodes.len(), 0, &edges)?
+            .into_iter()
+            .map(|(a, b, w)| (nodes[a], nodes[b], w))
+            .collect(),
+    )
+}
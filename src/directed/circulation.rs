@@ -0,0 +1,286 @@
+//! Find flows satisfying node demands and edge lower bounds, such as a
+//! [circulation](https://en.wikipedia.org/wiki/Circulation_problem), using any of
+//! the maximum flow algorithms implementing [`EdmondsKarp`].
+
+use indexmap::IndexSet;
+use num_traits::{Bounded, Signed, Zero};
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+use super::edmonds_karp::{EKFlows, EdmondsKarp};
+
+/// Find a circulation satisfying node demands and edge lower bounds, using the `EK`
+/// maximum flow algorithm, for example [`SparseCapacity`](super::edmonds_karp::SparseCapacity).
+///
+/// - `vertices` is the collection of vertices in the graph.
+/// - `demands` gives the demand of some vertices: the flow entering a vertex minus
+///   the flow leaving it must be equal to its demand, which is negative for vertices
+///   supplying flow. Vertices which are not listed have no demand.
+/// - `edges` is an iterator-like object describing the edges of the graph, as
+///   `((from, to), lower, upper)` triples: the flow going through the edge must lie
+///   between `lower` and `upper`, with `0 <= lower <= upper`. Several edges may link
+///   the same nodes.
+///
+/// If the demands can be satisfied, the function returns the flow going through the
+/// edges, in the order they have been given and omitting edges without flow.
+/// Otherwise it returns a set `Y` of vertices, proving the infeasibility: the total
+/// demand of `Y` is larger than the capacity of the edges entering `Y` minus the
+/// lower bounds of the edges leaving `Y`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // A factory supplies 5 units to two shops. At least 2 units must go through
+/// // the direct route to the first shop.
+/// let edges = [(('f', 'a'), 2, 3), (('f', 'b'), 0, 4), (('b', 'a'), 0, 1)];
+/// let demands = [('f', -5), ('a', 3), ('b', 2)];
+/// let flows = circulation::<_, _, _, _, SparseCapacity<_>>(&['f', 'a', 'b'], demands, edges);
+/// assert_eq!(flows, Ok(vec![(('f', 'a'), 3), (('f', 'b'), 2)]));
+///
+/// // The first shop cannot receive 5 units.
+/// let demands = [('f', -5), ('a', 5)];
+/// let flows = circulation::<_, _, _, _, SparseCapacity<_>>(&['f', 'a', 'b'], demands, edges);
+/// assert_eq!(flows, Err(vec!['a']));
+/// ```
+///
+/// # Panics
+///
+/// This function panics if a vertex is not found in `vertices`, or if the demands
+/// do not sum to zero.
+#[allow(clippy::type_complexity)]
+pub fn circulation<N, C, ID, IE, EK>(
+    vertices: &[N],
+    demands: ID,
+    edges: IE,
+) -> Result<Vec<((N, N), C)>, Vec<N>>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    ID: IntoIterator<Item = (N, C)>,
+    IE: IntoIterator<Item = ((N, N), C, C)>,
+    EK: EdmondsKarp<C>,
+{
+    let reverse = vertices.iter().collect::<IndexSet<_>>();
+    let mut balances = vec![C::zero(); vertices.len()];
+    for (vertex, demand) in demands {
+        let index = reverse.get_index_of(&vertex).unwrap();
+        balances[index] = balances[index] + demand;
+    }
+    assert!(
+        balances.iter().fold(C::zero(), |a, &b| a + b) == Zero::zero(),
+        "demands do not sum to zero"
+    );
+    let edges = index_edges(&reverse, edges);
+    let flows = feasible_flows::<C, EK>(balances, &edges, None)
+        .map_err(|side| side.into_iter().map(|n| vertices[n]).collect::<Vec<_>>())?;
+    Ok(edges
+        .iter()
+        .zip(flows)
+        .filter(|&(&(_, _, lower, _), flow)| lower + flow > Zero::zero())
+        .map(|(&(from, to, lower, _), flow)| ((vertices[from], vertices[to]), lower + flow))
+        .collect())
+}
+
+/// Compute the maximum flow going from `source` to `sink` through a directed graph
+/// whose edges have a lower bound, using the `EK` maximum flow algorithm, for example
+/// [`SparseCapacity`](super::edmonds_karp::SparseCapacity).
+///
+/// `edges` is an iterator-like object describing the edges of the graph, as
+/// `((from, to), lower, upper)` triples: the flow going through the edge must lie
+/// between `lower` and `upper`, with `0 <= lower <= upper`. Several edges may link
+/// the same nodes.
+///
+/// If a flow respecting the lower bounds exists, the function returns the flow going
+/// through the edges, in the order they have been given and omitting edges without
+/// flow, along with the total flow. Otherwise it returns a set `Y` of vertices,
+/// proving the infeasibility: the lower bounds of the edges leaving `Y` are larger
+/// than the capacity of the edges entering `Y`, and `Y` contains `sink` if it
+/// contains `source`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// let edges = [(('s', 'a'), 0, 4), (('a', 't'), 3, 5), (('s', 't'), 1, 2)];
+/// let result = max_flow_with_lower_bounds::<_, _, _, SparseCapacity<_>>(&['s', 'a', 't'], &'s', &'t', edges);
+/// assert_eq!(result, Ok((vec![(('s', 'a'), 4), (('a', 't'), 4), (('s', 't'), 2)], 6)));
+///
+/// // At least 3 units must leave 'a', but only 2 can enter it.
+/// let edges = [(('s', 'a'), 0, 2), (('a', 't'), 3, 5)];
+/// let result = max_flow_with_lower_bounds::<_, _, _, SparseCapacity<_>>(&['s', 'a', 't'], &'s', &'t', edges);
+/// assert_eq!(result, Err(vec!['a']));
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `source`, `sink`, or an edge end is not found in
+/// `vertices`.
+pub fn max_flow_with_lower_bounds<N, C, IE, EK>(
+    vertices: &[N],
+    source: &N,
+    sink: &N,
+    edges: IE,
+) -> Result<EKFlows<N, C>, Vec<N>>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IE: IntoIterator<Item = ((N, N), C, C)>,
+    EK: EdmondsKarp<C>,
+{
+    let reverse = vertices.iter().collect::<IndexSet<_>>();
+    let source = reverse.get_index_of(source).unwrap();
+    let sink = reverse.get_index_of(sink).unwrap();
+    let edges = index_edges(&reverse, edges);
+    // Look for a feasible circulation when the sink can send back any amount of flow
+    // to the source, then push more flow from the source to the sink.
+    let flows = feasible_flows::<C, EK>(
+        vec![C::zero(); vertices.len()],
+        &edges,
+        Some((sink, source)),
+    )
+    .map_err(|side| side.into_iter().map(|n| vertices[n]).collect::<Vec<_>>())?;
+    let mut network = EK::new(vertices.len(), source, sink);
+    network.omit_detailed_flows();
+    for ((from, to), capacity) in aggregate_capacities(&edges) {
+        network.set_capacity(from, to, capacity);
+    }
+    for (&(from, to, _, _), &flow) in edges.iter().zip(&flows) {
+        if flow > Zero::zero() {
+            network.add_flow(from, to, flow);
+        }
+    }
+    network.augment();
+    let flows = distribute(&edges, |from, to| network.flow(from, to));
+    let mut total = C::zero();
+    let mut result = Vec::new();
+    for (&(from, to, lower, _), flow) in edges.iter().zip(flows) {
+        let flow = lower + flow;
+        if from != to {
+            if from == source {
+                total = total + flow;
+            } else if to == source {
+                total = total - flow;
+            }
+        }
+        if flow > Zero::zero() {
+            result.push(((vertices[from], vertices[to]), flow));
+        }
+    }
+    Ok((result, total))
+}
+
+fn index_edges<N, C, IE>(reverse: &IndexSet<&N>, edges: IE) -> Vec<(usize, usize, C, C)>
+where
+    N: Eq + Hash,
+    IE: IntoIterator<Item = ((N, N), C, C)>,
+{
+    edges
+        .into_iter()
+        .map(|((from, to), lower, upper)| {
+            (
+                reverse.get_index_of(&from).unwrap(),
+                reverse.get_index_of(&to).unwrap(),
+                lower,
+                upper,
+            )
+        })
+        .collect()
+}
+
+// Sum the capacities above the lower bounds of the edges linking the same nodes,
+// ignoring loops.
+fn aggregate_capacities<C>(edges: &[(usize, usize, C, C)]) -> BTreeMap<(usize, usize), C>
+where
+    C: Zero + Signed + Copy,
+{
+    let mut capacities = BTreeMap::new();
+    for &(from, to, lower, upper) in edges {
+        if from != to {
+            let capacity = capacities.entry((from, to)).or_insert_with(C::zero);
+            *capacity = *capacity + upper - lower;
+        }
+    }
+    capacities
+}
+
+// Share the flow between two nodes, as given by `flow`, amongst the edges linking
+// them, and return the flow going through every edge above its lower bound.
+fn distribute<C, F>(edges: &[(usize, usize, C, C)], mut flow: F) -> Vec<C>
+where
+    C: Zero + Signed + Ord + Copy,
+    F: FnMut(usize, usize) -> C,
+{
+    let mut remaining = BTreeMap::new();
+    edges
+        .iter()
+        .map(|&(from, to, lower, upper)| {
+            if from == to {
+                return C::zero();
+            }
+            let remaining = remaining
+                .entry((from, to))
+                .or_insert_with(|| flow(from, to).max(Zero::zero()));
+            let share = (*remaining).min(upper - lower);
+            *remaining = *remaining - share;
+            share
+        })
+        .collect()
+}
+
+// Find a flow satisfying the given balances (demands reduced by the lower bounds),
+// and return the flow going through every edge above its lower bound. If it does
+// not exist, return the vertices which cannot receive enough flow. The optional
+// extra edge has an unlimited capacity.
+fn feasible_flows<C, EK>(
+    mut balances: Vec<C>,
+    edges: &[(usize, usize, C, C)],
+    extra: Option<(usize, usize)>,
+) -> Result<Vec<C>, Vec<usize>>
+where
+    C: Zero + Bounded + Signed + Ord + Copy,
+    EK: EdmondsKarp<C>,
+{
+    let size = balances.len();
+    for &(from, to, lower, _) in edges {
+        balances[from] = balances[from] + lower;
+        balances[to] = balances[to] - lower;
+    }
+    let mut capacities = aggregate_capacities(edges);
+    if let Some((from, to)) = extra {
+        let unlimited = edges
+            .iter()
+            .fold(C::zero(), |total, &(_, _, _, upper)| total + upper);
+        let capacity = capacities.entry((from, to)).or_insert_with(C::zero);
+        *capacity = *capacity + unlimited;
+    }
+    // A balance is the flow entering a vertex minus the flow leaving it. Vertices
+    // with a positive balance absorb flow, which they send to the sink, while
+    // vertices with a negative one supply flow, which they receive from the source.
+    let (source, sink) = (size, size + 1);
+    let mut network = EK::new(size + 2, source, sink);
+    network.omit_detailed_flows();
+    let mut required = C::zero();
+    for (vertex, &balance) in balances.iter().enumerate() {
+        if balance > Zero::zero() {
+            network.set_capacity(vertex, sink, balance);
+            required = required + balance;
+        } else if balance < Zero::zero() {
+            network.set_capacity(source, vertex, -balance);
+        }
+    }
+    for (&(from, to), &capacity) in &capacities {
+        network.set_capacity(from, to, capacity);
+    }
+    if network.augment().1 < required {
+        let (side, _) = network.min_cut();
+        let mut on_source_side = vec![false; size + 2];
+        for n in side {
+            on_source_side[n] = true;
+        }
+        return Err((0..size).filter(|&n| !on_source_side[n]).collect());
+    }
+    Ok(distribute(edges, |from, to| network.flow(from, to)))
+}
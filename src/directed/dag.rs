@@ -0,0 +1,248 @@
+//! Compute shortest and longest paths in a directed acyclic graph in linear time,
+//! as well as schedules using the [critical path
+//! method](https://en.wikipedia.org/wiki/Critical_path_method).
+//!
+//! All functions explore the graph in the order given by
+//! [`topological_sort`](super::topological_sort::topological_sort), and return
+//! `Err` with a node belonging to a cycle if the graph is not acyclic.
+
+use num_traits::Zero;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use super::topological_sort::topological_sort;
+
+/// Sort the graph reachable from `roots` and remember the weighted successors
+/// of every node, so that `successors` is called only once per node.
+#[allow(clippy::type_complexity)]
+fn sorted_graph<N, C, FN, IN>(
+    roots: &[N],
+    mut successors: FN,
+) -> Result<(Vec<N>, HashMap<N, Vec<(N, C)>>), N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut edges = HashMap::new();
+    let sorted = topological_sort(roots, |n| {
+        let succs = successors(n).into_iter().collect::<Vec<_>>();
+        let nodes = succs.iter().map(|(s, _)| s.clone()).collect::<Vec<_>>();
+        edges.insert(n.clone(), succs);
+        nodes
+    })?;
+    Ok((sorted, edges))
+}
+
+fn dag_paths<N, C, FN, IN>(
+    start: &N,
+    successors: FN,
+    better: fn(&C, &C) -> bool,
+) -> Result<HashMap<N, (N, C)>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (sorted, edges) = sorted_graph(std::slice::from_ref(start), successors)?;
+    let mut parents: HashMap<N, (N, C)> = HashMap::new();
+    for node in &sorted {
+        let cost = if node == start {
+            Zero::zero()
+        } else {
+            parents[node].1
+        };
+        for (succ, move_cost) in &edges[node] {
+            let new_cost = cost + *move_cost;
+            if succ != start
+                && parents
+                    .get(succ)
+                    .map_or(true, |&(_, c)| better(&new_cost, &c))
+            {
+                parents.insert(succ.clone(), (node.clone(), new_cost));
+            }
+        }
+    }
+    Ok(parents)
+}
+
+/// Determine all reachable nodes from a starting point in a directed acyclic graph,
+/// as well as the minimum cost to reach them and a possible optimal parent node.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. Costs may be negative.
+///
+/// The result is a map where every reachable node (not including `start`) is associated with
+/// an optimal parent node and a cost from the start node, in the same format as
+/// [`dijkstra_all`](super::dijkstra::dijkstra_all). The
+/// [`build_path`](super::dijkstra::build_path) function can be used to build a full path
+/// from the starting point to one of the reachable targets.
+///
+/// The function returns `Err` with a node belonging to a cycle if such a cycle is reachable
+/// from `start`. It runs in O(|V| + |E|) time.
+pub fn dag_shortest_paths<N, C, FN, IN>(start: &N, successors: FN) -> Result<HashMap<N, (N, C)>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    dag_paths(start, successors, |new, old| new < old)
+}
+
+/// Determine all reachable nodes from a starting point in a directed acyclic graph,
+/// as well as the maximum cost to reach them and a possible optimal parent node.
+///
+/// This function works like [`dag_shortest_paths`], but looks for the longest paths
+/// instead.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{build_path, dag_longest_paths};
+///
+/// let successors = |&n: &char| match n {
+///     'a' => vec![('b', 2), ('c', 1)],
+///     'b' => vec![('d', 1)],
+///     'c' => vec![('d', 5)],
+///     _ => vec![],
+/// };
+/// let parents = dag_longest_paths(&'a', successors).unwrap();
+/// assert_eq!(parents[&'d'].1, 6);
+/// assert_eq!(build_path(&'d', &parents), vec!['a', 'c', 'd']);
+/// ```
+pub fn dag_longest_paths<N, C, FN, IN>(start: &N, successors: FN) -> Result<HashMap<N, (N, C)>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    dag_paths(start, successors, |new, old| new > old)
+}
+
+/// Timing of a task as computed by [`critical_path`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TaskTimes<C> {
+    /// Earliest time at which the task can start.
+    pub earliest_start: C,
+    /// Latest time at which the task can start without delaying the whole schedule.
+    pub latest_start: C,
+    /// Delay the task can take without delaying the whole schedule.
+    pub slack: C,
+}
+
+/// Schedule computed by [`critical_path`].
+#[derive(Clone, Debug)]
+pub struct Schedule<N, C> {
+    /// Total duration of the schedule.
+    pub duration: C,
+    /// Timing of every task.
+    pub times: HashMap<N, TaskTimes<C>>,
+    /// One of the longest chains of tasks, all of them having no slack.
+    pub critical_path: Vec<N>,
+}
+
+/// Compute a schedule using the [critical path
+/// method](https://en.wikipedia.org/wiki/Critical_path_method).
+///
+/// - `tasks` is a collection of tasks.
+/// - `successors` returns the tasks which can only start once a given task is
+///   finished, including possibly tasks that were not present in `tasks`.
+/// - `duration` returns the duration of a given task.
+///
+/// Tasks start at time zero at the earliest. The function returns either `Ok` with the
+/// [`Schedule`], or `Err` with a task belonging to a dependency cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::critical_path;
+///
+/// // Making tea: boiling water (5) and fetching a cup (1) can be done in parallel,
+/// // and must both be completed before brewing (3).
+/// let successors = |&t: &&str| if t == "brew" { vec![] } else { vec!["brew"] };
+/// let duration = |&t: &&str| match t {
+///     "boil" => 5,
+///     "cup" => 1,
+///     _ => 3,
+/// };
+/// let schedule = critical_path(&["cup", "boil"], successors, duration).unwrap();
+/// assert_eq!(schedule.duration, 8);
+/// assert_eq!(schedule.critical_path, vec!["boil", "brew"]);
+/// assert_eq!(schedule.times["cup"].slack, 4);
+/// assert_eq!(schedule.times["brew"].earliest_start, 5);
+/// ```
+pub fn critical_path<N, C, FN, IN, FD>(
+    tasks: &[N],
+    mut successors: FN,
+    mut duration: FD,
+) -> Result<Schedule<N, C>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FD: FnMut(&N) -> C,
+{
+    let (sorted, edges) = sorted_graph(tasks, |n| successors(n).into_iter().map(|s| (s, ())))?;
+    let durations = sorted
+        .iter()
+        .map(|n| (n, duration(n)))
+        .collect::<HashMap<_, _>>();
+    let mut earliest: HashMap<&N, C> = HashMap::with_capacity(sorted.len());
+    let mut total = C::zero();
+    for node in &sorted {
+        let finish = *earliest.entry(node).or_insert_with(Zero::zero) + durations[node];
+        total = total.max(finish);
+        for (succ, _) in &edges[node] {
+            let start = earliest.entry(succ).or_insert_with(Zero::zero);
+            *start = (*start).max(finish);
+        }
+    }
+    let mut latest: HashMap<&N, C> = HashMap::with_capacity(sorted.len());
+    for node in sorted.iter().rev() {
+        let finish = edges[node]
+            .iter()
+            .map(|(succ, _)| latest[succ])
+            .min()
+            .unwrap_or(total);
+        latest.insert(node, finish - durations[node]);
+    }
+    let times = sorted
+        .iter()
+        .map(|n| {
+            let (earliest_start, latest_start) = (earliest[n], latest[n]);
+            let slack = latest_start - earliest_start;
+            (
+                n.clone(),
+                TaskTimes {
+                    earliest_start,
+                    latest_start,
+                    slack,
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let is_critical = |n: &N| times[n].slack == Zero::zero();
+    let mut critical_path = Vec::new();
+    let mut next = sorted
+        .iter()
+        .find(|n| is_critical(n) && times[*n].earliest_start == Zero::zero());
+    while let Some(node) = next {
+        critical_path.push(node.clone());
+        let finish = times[node].earliest_start + durations[node];
+        next = edges[node]
+            .iter()
+            .map(|(s, _)| s)
+            .find(|s| is_critical(s) && times[*s].earliest_start == finish);
+    }
+    Ok(Schedule {
+        duration: total,
+        times,
+        critical_path,
+    })
+}
@@ -20,6 +20,11 @@ use crate::matrix::Matrix;
 #[allow(clippy::upper_case_acronyms)]
 pub type EKFlows<N, C> = (Vec<((N, N), C)>, C);
 
+/// Type alias for a minimum cut: the nodes on the source side, and the edges
+/// leaving them with their capacity.
+#[allow(clippy::upper_case_acronyms)]
+pub type EKCut<N, C> = (Vec<N>, Vec<((N, N), C)>);
+
 /// Compute the maximum flow that can go through a directed graph using the
 /// [Edmonds Karp algorithm](https://en.wikipedia.org/wiki/Edmonds–Karp_algorithm).
 ///
@@ -50,8 +55,25 @@ where
     IC: IntoIterator<Item = ((N, N), C)>,
     EK: EdmondsKarp<C>,
 {
-    // Build a correspondence between N and 0..vertices.len() so that we can
-    // work with matrices more easily.
+    let (paths, max) = network::<N, C, IC, EK>(vertices, source, sink, caps).augment();
+    (
+        paths
+            .into_iter()
+            .map(|((a, b), c)| ((vertices[a], vertices[b]), c))
+            .collect(),
+        max,
+    )
+}
+
+// Build a correspondence between N and 0..vertices.len() so that we can
+// work with matrices more easily.
+fn network<N, C, IC, EK>(vertices: &[N], source: &N, sink: &N, caps: IC) -> EK
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IC: IntoIterator<Item = ((N, N), C)>,
+    EK: EdmondsKarp<C>,
+{
     let reverse = vertices.iter().collect::<IndexSet<_>>();
     let mut capacities = EK::new(
         vertices.len(),
@@ -65,13 +87,49 @@ where
             capacity,
         );
     }
-    let (paths, max) = capacities.augment();
+    capacities
+}
+
+/// Compute a minimum cut separating `source` from `sink` in a directed graph, using
+/// the maximum flow computed by the `EK` algorithm.
+///
+/// The arguments are the same as the ones of [`edmonds_karp`]. The function returns
+/// the vertices on the source side of the cut, in the order they appear in `vertices`,
+/// and the edges going from those vertices to the other ones along with their
+/// capacity. The capacity of the cut, which is also the value of the maximum flow,
+/// is the sum of those capacities.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// let caps = [(('s', 'a'), 3), (('s', 'b'), 2), (('a', 'b'), 1), (('a', 't'), 1), (('b', 't'), 4)];
+/// let (side, edges) = min_cut::<_, _, _, SparseCapacity<_>>(&['s', 'a', 'b', 't'], &'s', &'t', caps);
+/// assert_eq!(side, vec!['s', 'a']);
+/// assert_eq!(edges, vec![(('s', 'b'), 2), (('a', 'b'), 1), (('a', 't'), 1)]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `source` or `sink` is not found in `vertices`.
+pub fn min_cut<N, C, IC, EK>(vertices: &[N], source: &N, sink: &N, caps: IC) -> EKCut<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IC: IntoIterator<Item = ((N, N), C)>,
+    EK: EdmondsKarp<C>,
+{
+    let mut network = network::<N, C, IC, EK>(vertices, source, sink, caps);
+    network.omit_detailed_flows();
+    network.augment();
+    let (side, edges) = network.min_cut();
     (
-        paths
+        side.into_iter().map(|n| vertices[n]).collect(),
+        edges
             .into_iter()
             .map(|((a, b), c)| ((vertices[a], vertices[b]), c))
             .collect(),
-        max,
     )
 }
 
@@ -268,6 +326,38 @@ pub trait EdmondsKarp<C: Copy + Zero + Signed + Ord + Bounded> {
         }
     }
 
+    /// Compute a minimum cut once the maximum flow has been computed by
+    /// [`augment`](Self::augment). Return the nodes reachable from the source in
+    /// the residual graph, in increasing order, and the saturated edges going from
+    /// them to the other nodes along with their capacity.
+    fn min_cut(&self) -> EKCut<usize, C> {
+        let mut on_source_side = vec![false; self.size()];
+        on_source_side[self.source()] = true;
+        let mut to_see = vec![self.source()];
+        while let Some(node) = to_see.pop() {
+            for (successor, _) in self.residual_successors(node) {
+                if !on_source_side[successor] {
+                    on_source_side[successor] = true;
+                    to_see.push(successor);
+                }
+            }
+        }
+        let side = (0..self.size())
+            .filter(|&n| on_source_side[n])
+            .collect::<Vec<_>>();
+        let mut edges = side
+            .iter()
+            .flat_map(|&from| {
+                self.flows_from(from)
+                    .into_iter()
+                    .filter(|&to| !on_source_side[to])
+                    .map(move |to| ((from, to), self.flow(from, to)))
+            })
+            .collect::<Vec<_>>();
+        edges.sort_unstable_by_key(|&(edge, _)| edge);
+        (side, edges)
+    }
+
     /// Internal: cancel a flow capacity between two nodes.
     fn cancel_flow(&mut self, from: usize, to: usize, mut capacity: C) {
         if from == to {
@@ -0,0 +1,202 @@
+//! Enumerate the elementary cycles of a finite directed graph using
+//! [Johnson's algorithm](https://doi.org/10.1137/0204007).
+
+use fixedbitset::FixedBitSet;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+
+use super::strongly_connected_components::strongly_connected_component;
+use super::{index_graph, FxIndexSet};
+
+/// Enumerate the elementary cycles of a finite directed graph, that is the cycles
+/// in which no node appears twice.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// Cycles are generated lazily, so that only the requested ones are computed. As in
+/// [`bfs_loop`](super::bfs::bfs_loop), the first node of every cycle is repeated at
+/// its end. The length of the cycles can be limited using
+/// [`ElementaryCycles::max_length`], and their number by using
+/// [`Iterator::take`].
+///
+/// The current implementation uses [Johnson's
+/// algorithm](https://doi.org/10.1137/0204007), which spends O(|V| + |E|) time
+/// between two consecutive cycles when the length is not limited.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::elementary_cycles;
+///
+/// // 1 -> 2 -> 3 -> 1, and 2 -> 1.
+/// let successors = |&n: &u32| match n {
+///     1 => vec![2],
+///     2 => vec![3, 1],
+///     _ => vec![1],
+/// };
+/// let cycles = elementary_cycles(&[1, 2, 3], successors).collect::<Vec<_>>();
+/// assert_eq!(cycles, vec![vec![1, 2, 1], vec![1, 2, 3, 1]]);
+///
+/// let short = elementary_cycles(&[1, 2, 3], successors).max_length(2).collect::<Vec<_>>();
+/// assert_eq!(short, vec![vec![1, 2, 1]]);
+/// ```
+pub fn elementary_cycles<N, FN, IN>(nodes: &[N], successors: FN) -> ElementaryCycles<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, mut graph) = index_graph(nodes, successors);
+    for succs in &mut graph {
+        succs.sort_unstable();
+        succs.dedup();
+    }
+    let size = nodes.len();
+    ElementaryCycles {
+        nodes,
+        graph,
+        max_length: usize::MAX,
+        start: None,
+        component: FixedBitSet::with_capacity(size),
+        blocked: FixedBitSet::with_capacity(size),
+        blocked_by: vec![Vec::new(); size],
+        path: Vec::new(),
+    }
+}
+
+/// Struct returned by [`elementary_cycles`](crate::directed::elementary_cycles::elementary_cycles).
+pub struct ElementaryCycles<N> {
+    nodes: FxIndexSet<N>,
+    graph: Vec<Vec<usize>>,
+    max_length: usize,
+    // Smallest node of the cycles being looked for, and strongly connected
+    // component containing it amongst the nodes greater or equal.
+    start: Option<usize>,
+    component: FixedBitSet,
+    blocked: FixedBitSet,
+    blocked_by: Vec<Vec<usize>>,
+    path: Vec<Step>,
+}
+
+struct Step {
+    node: usize,
+    next_successor: usize,
+    // Set if a cycle has been found from this node, or if the length limit
+    // prevented the exploration of some of its successors.
+    unblock: bool,
+}
+
+impl<N> ElementaryCycles<N> {
+    /// Only generate cycles with at most `max_length` edges.
+    #[must_use]
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    // Look for the next strongly connected component containing a cycle, return
+    // `false` if there is none.
+    fn next_start(&mut self) -> bool {
+        let mut start = self.start.map_or(0, |s| s + 1);
+        while start < self.nodes.len() {
+            let graph = &self.graph;
+            let component = strongly_connected_component(&start, |&n| {
+                graph[n].iter().copied().filter(move |&s| s >= start)
+            });
+            if component.len() > 1 || graph[start].contains(&start) {
+                self.component.clear();
+                for n in component {
+                    self.component.insert(n);
+                    self.blocked.set(n, false);
+                    self.blocked_by[n].clear();
+                }
+                self.start = Some(start);
+                self.push(start);
+                return true;
+            }
+            start += 1;
+        }
+        self.start = Some(start);
+        false
+    }
+
+    fn push(&mut self, node: usize) {
+        self.blocked.insert(node);
+        self.path.push(Step {
+            node,
+            next_successor: 0,
+            unblock: false,
+        });
+    }
+
+    fn unblock(&mut self, node: usize) {
+        let mut to_unblock = vec![node];
+        while let Some(n) = to_unblock.pop() {
+            if self.blocked[n] {
+                self.blocked.set(n, false);
+                to_unblock.append(&mut self.blocked_by[n]);
+            }
+        }
+    }
+}
+
+impl<N> Iterator for ElementaryCycles<N>
+where
+    N: Clone,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.path.is_empty() && !self.next_start() {
+                return None;
+            }
+            let depth = self.path.len();
+            let step = self.path.last_mut().unwrap();
+            let node = step.node;
+            if let Some(&succ) = self.graph[node].get(step.next_successor) {
+                step.next_successor += 1;
+                if !self.component[succ] {
+                    continue;
+                }
+                if Some(succ) == self.start && depth <= self.max_length {
+                    step.unblock = true;
+                    let cycle = self
+                        .path
+                        .iter()
+                        .map(|s| s.node)
+                        .chain(self.start)
+                        .map(|n| self.nodes[n].clone())
+                        .collect();
+                    return Some(cycle);
+                }
+                if !self.blocked[succ] {
+                    if depth < self.max_length {
+                        self.push(succ);
+                    } else {
+                        step.unblock = true;
+                    }
+                }
+            } else {
+                let unblock = step.unblock;
+                self.path.pop();
+                if unblock {
+                    self.unblock(node);
+                    if let Some(parent) = self.path.last_mut() {
+                        parent.unblock = true;
+                    }
+                } else {
+                    for &succ in &self.graph[node] {
+                        if self.component[succ] && !self.blocked_by[succ].contains(&node) {
+                            self.blocked_by[succ].push(node);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<N> FusedIterator for ElementaryCycles<N> where N: Clone {}
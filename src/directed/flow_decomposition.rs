@@ -0,0 +1,192 @@
+//! Decompose a flow, such as the one returned by
+//! [`edmonds_karp`](super::edmonds_karp::edmonds_karp), into paths going from the
+//! source to the sink and cycles, along with the flow they carry.
+
+use num_traits::Zero;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use super::FxIndexSet;
+
+/// Type alias for a decomposed flow: the paths going from the source to the sink,
+/// and the cycles, along with the flow going through them.
+pub type DecomposedFlows<N, C> = (Vec<(Vec<N>, C)>, Vec<(Vec<N>, C)>);
+
+/// Decompose a flow going from `source` to `sink` into paths and cycles.
+///
+/// - `source` is the source node (the origin of the flow).
+/// - `sink` is the sink node (the target of the flow).
+/// - `flows` gives the flow going through every edge, for example the first
+///   element of the [`EKFlows`](super::edmonds_karp::EKFlows) returned by
+///   [`edmonds_karp`](super::edmonds_karp::edmonds_karp). Edges whose flow is not
+///   positive are ignored.
+///
+/// The function returns the paths going from `source` to `sink`, then the cycles
+/// remaining once those paths have been removed from the flow, along with the flow
+/// going through every one of them. Paths and cycles never go twice through the same
+/// node, and cycles start and end with the same node. The flow going through the
+/// paths sums to the total flow, and the flow going through an edge is the sum of
+/// the flows of the paths and cycles using it.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// let flows = [
+///     (('s', 'a'), 3),
+///     (('s', 'b'), 1),
+///     (('a', 'b'), 1),
+///     (('a', 't'), 2),
+///     (('b', 't'), 2),
+///     (('c', 'd'), 4),
+///     (('d', 'c'), 4),
+/// ];
+/// let (paths, cycles) = decompose_flows(&'s', &'t', &flows);
+/// assert_eq!(
+///     paths,
+///     vec![
+///         (vec!['s', 'a', 'b', 't'], 1),
+///         (vec!['s', 'a', 't'], 2),
+///         (vec!['s', 'b', 't'], 1),
+///     ]
+/// );
+/// assert_eq!(cycles, vec![(vec!['c', 'd', 'c'], 4)]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if the flow is not conserved, that is if a node other than
+/// `source` and `sink` receives more or less flow than it sends, or if `source`
+/// receives more flow than it sends.
+pub fn decompose_flows<N, C>(source: &N, sink: &N, flows: &[((N, N), C)]) -> DecomposedFlows<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Sub<Output = C> + Ord + Copy,
+{
+    let mut nodes = FxIndexSet::default();
+    let source = nodes.insert_full(source.clone()).0;
+    let sink = nodes.insert_full(sink.clone()).0;
+    let mut remaining = vec![Vec::new(); nodes.len()];
+    let (mut outflow, mut inflow) = (C::zero(), C::zero());
+    for ((from, to), flow) in flows {
+        let from = nodes.insert_full(from.clone()).0;
+        let to = nodes.insert_full(to.clone()).0;
+        remaining.resize_with(nodes.len(), Vec::new);
+        if *flow > Zero::zero() {
+            remaining[from].push((to, *flow));
+            if from == source {
+                outflow = outflow + *flow;
+            }
+            if to == source {
+                inflow = inflow + *flow;
+            }
+        }
+    }
+    // Send the total flow back from the sink to the source through an extra edge, so
+    // that the paths are found as cycles going through it.
+    let mut extra = None;
+    if source != sink {
+        assert!(inflow <= outflow, "flow is not conserved");
+        if outflow > inflow {
+            extra = Some((sink, remaining[sink].len()));
+            remaining[sink].push((source, outflow - inflow));
+        }
+    }
+    let mut decomposition = Decomposition {
+        next: vec![0; nodes.len()],
+        position: vec![usize::MAX; nodes.len()],
+        remaining,
+        extra,
+        walk: Vec::new(),
+        paths: Vec::new(),
+        cycles: Vec::new(),
+    };
+    for node in 0..nodes.len() {
+        decomposition.follow(node);
+    }
+    let to_nodes = |(path, flow): (Vec<usize>, C)| {
+        (path.into_iter().map(|n| nodes[n].clone()).collect(), flow)
+    };
+    (
+        decomposition.paths.into_iter().map(to_nodes).collect(),
+        decomposition.cycles.into_iter().map(to_nodes).collect(),
+    )
+}
+
+struct Decomposition<C> {
+    // Edges leaving every node, with the flow which has not been assigned yet.
+    remaining: Vec<Vec<(usize, C)>>,
+    // Index of the first edge leaving every node which may still carry flow.
+    next: Vec<usize>,
+    // Position of every node in the current walk, if any.
+    position: Vec<usize>,
+    // Extra edge going from the sink to the source, if any.
+    extra: Option<(usize, usize)>,
+    // Nodes of the current walk, with the index of the edge leaving them.
+    walk: Vec<(usize, usize)>,
+    paths: Vec<(Vec<usize>, C)>,
+    cycles: Vec<(Vec<usize>, C)>,
+}
+
+impl<C> Decomposition<C>
+where
+    C: Zero + Sub<Output = C> + Ord + Copy,
+{
+    // Follow the flow leaving `start` and remove the cycles found on the way until
+    // no flow leaves it anymore.
+    fn follow(&mut self, start: usize) {
+        let mut node = start;
+        self.position[start] = 0;
+        loop {
+            while self.next[node] < self.remaining[node].len()
+                && self.remaining[node][self.next[node]].1 == Zero::zero()
+            {
+                self.next[node] += 1;
+            }
+            if self.next[node] == self.remaining[node].len() {
+                assert!(self.walk.is_empty(), "flow is not conserved");
+                self.position[node] = usize::MAX;
+                return;
+            }
+            self.walk.push((node, self.next[node]));
+            node = self.remaining[node][self.next[node]].0;
+            if self.position[node] == usize::MAX {
+                self.position[node] = self.walk.len();
+            } else {
+                self.remove_cycle(self.position[node]);
+            }
+        }
+    }
+
+    // Remove the cycle formed by the edges of the walk starting at position `from`,
+    // along with the flow going through all of them. The cycle is recorded as a path
+    // if it goes through the extra edge.
+    fn remove_cycle(&mut self, from: usize) {
+        let flow = self.walk[from..]
+            .iter()
+            .map(|&(node, edge)| self.remaining[node][edge].1)
+            .min()
+            .unwrap();
+        let mut extra = None;
+        let mut nodes = Vec::with_capacity(self.walk.len() - from + 1);
+        for (i, (node, edge)) in self.walk.drain(from..).enumerate() {
+            let remaining = &mut self.remaining[node][edge].1;
+            *remaining = *remaining - flow;
+            if Some((node, edge)) == self.extra {
+                extra = Some(i);
+            }
+            if i > 0 {
+                self.position[node] = usize::MAX;
+            }
+            nodes.push(node);
+        }
+        if let Some(i) = extra {
+            nodes.rotate_left(i + 1);
+            self.paths.push((nodes, flow));
+        } else {
+            nodes.push(nodes[0]);
+            self.cycles.push((nodes, flow));
+        }
+    }
+}
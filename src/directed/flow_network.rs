@@ -0,0 +1,181 @@
+//! Build maximum flow problems with several sources and sinks, and with vertex
+//! capacities, and solve them using any of the maximum flow algorithms implementing
+//! [`EdmondsKarp`].
+
+use num_traits::{Bounded, Signed, Zero};
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+use super::edmonds_karp::{EKFlows, EdmondsKarp};
+use super::FxIndexSet;
+
+/// Flow network with several sources and sinks, whose vertices may have a capacity.
+///
+/// The maximum flow is computed by adding a super-source linked to every source and
+/// a super-sink linked from every sink, and by splitting vertices with a capacity
+/// into an entering and a leaving vertex. The flows are mapped back to the edges of
+/// the original network.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // Two factories supplying two shops through a warehouse which can handle
+/// // at most 6 units.
+/// let mut network = FlowNetwork::new();
+/// network.add_edge('f', 'w', 5);
+/// network.add_edge('g', 'w', 5);
+/// network.add_edge('g', 'b', 1);
+/// network.add_edge('w', 'a', 4);
+/// network.add_edge('w', 'b', 4);
+/// network.add_source('f', None);
+/// network.add_source('g', Some(3));
+/// network.add_sink('a', None);
+/// network.add_sink('b', None);
+/// network.set_vertex_capacity('w', 6);
+/// let (flows, total) = network.max_flow::<SparseCapacity<_>>();
+/// assert_eq!(total, 7);
+/// assert_eq!(flows.iter().filter(|((_, to), _)| *to == 'w').map(|(_, f)| f).sum::<i32>(), 6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FlowNetwork<N, C> {
+    vertices: FxIndexSet<N>,
+    capacities: BTreeMap<(usize, usize), C>,
+    sources: BTreeMap<usize, Option<C>>,
+    sinks: BTreeMap<usize, Option<C>>,
+    vertex_capacities: BTreeMap<usize, C>,
+}
+
+impl<N, C> Default for FlowNetwork<N, C> {
+    fn default() -> Self {
+        Self {
+            vertices: FxIndexSet::default(),
+            capacities: BTreeMap::new(),
+            sources: BTreeMap::new(),
+            sinks: BTreeMap::new(),
+            vertex_capacities: BTreeMap::new(),
+        }
+    }
+}
+
+impl<N, C> FlowNetwork<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Bounded + Signed + Ord + Copy,
+{
+    /// Create a new empty network.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn index(&mut self, vertex: N) -> usize {
+        self.vertices.insert_full(vertex).0
+    }
+
+    /// Add an edge going from `from` to `to` with the given capacity. If an edge
+    /// already links those vertices, the capacities are summed. Loops are ignored.
+    pub fn add_edge(&mut self, from: N, to: N, capacity: C) {
+        let from = self.index(from);
+        let to = self.index(to);
+        if from != to {
+            let total = self.capacities.entry((from, to)).or_insert_with(C::zero);
+            *total = *total + capacity;
+        }
+    }
+
+    /// Make `source` a source of the flow, which can supply at most `supply`, or an
+    /// unlimited amount if `supply` is `None`. If `source` is already a source, its
+    /// supply is replaced.
+    pub fn add_source(&mut self, source: N, supply: Option<C>) {
+        let source = self.index(source);
+        self.sources.insert(source, supply);
+    }
+
+    /// Make `sink` a sink of the flow, which can absorb at most `demand`, or an
+    /// unlimited amount if `demand` is `None`. If `sink` is already a sink, its
+    /// demand is replaced.
+    pub fn add_sink(&mut self, sink: N, demand: Option<C>) {
+        let sink = self.index(sink);
+        self.sinks.insert(sink, demand);
+    }
+
+    /// Limit the flow going through `vertex` to `capacity`. For a source, this
+    /// includes the flow it supplies, and for a sink the flow it absorbs.
+    pub fn set_vertex_capacity(&mut self, vertex: N, capacity: C) {
+        let vertex = self.index(vertex);
+        self.vertex_capacities.insert(vertex, capacity);
+    }
+
+    /// Compute the maximum flow going from the sources to the sinks using the `EK`
+    /// maximum flow algorithm, for example
+    /// [`SparseCapacity`](super::edmonds_karp::SparseCapacity) or
+    /// [`Dinic<SparseCapacity>`](super::dinic::Dinic).
+    ///
+    /// The flows going through the edges of the network are returned along with the
+    /// total flow. The flow going through vertices, supplied by sources or absorbed by
+    /// sinks can be deduced from them.
+    #[must_use]
+    pub fn max_flow<EK: EdmondsKarp<C>>(&self) -> EKFlows<N, C> {
+        // Vertices with a capacity are split into an entering vertex, keeping the
+        // original index, and a leaving vertex added after the original ones.
+        let size = self.vertices.len();
+        let mut leaving = (0..size).collect::<Vec<_>>();
+        let mut owners = (0..size).collect::<Vec<_>>();
+        for &vertex in self.vertex_capacities.keys() {
+            leaving[vertex] = owners.len();
+            owners.push(vertex);
+        }
+        let (source, sink) = (owners.len(), owners.len() + 1);
+        owners.extend([usize::MAX, usize::MAX]);
+        let mut network = EK::new(owners.len(), source, sink);
+        for (&vertex, &capacity) in &self.vertex_capacities {
+            network.set_capacity(vertex, leaving[vertex], capacity);
+        }
+        for (&(from, to), &capacity) in &self.capacities {
+            network.set_capacity(leaving[from], to, capacity);
+        }
+        // An unlimited supply is bounded by the capacity of the edges leaving the
+        // source, and an unlimited demand by the capacity of the edges entering the sink.
+        for (&vertex, &supply) in &self.sources {
+            let supply = supply.unwrap_or_else(|| {
+                self.capacities
+                    .iter()
+                    .filter(|&(&(from, _), _)| from == vertex)
+                    .fold(C::zero(), |total, (_, &capacity)| total + capacity)
+            });
+            network.set_capacity(source, vertex, supply);
+        }
+        for (&vertex, &demand) in &self.sinks {
+            let demand = demand.unwrap_or_else(|| {
+                self.capacities
+                    .iter()
+                    .filter(|&(&(_, to), _)| to == vertex)
+                    .fold(C::zero(), |total, (_, &capacity)| total + capacity)
+            });
+            network.set_capacity(leaving[vertex], sink, demand);
+        }
+        let (flows, total) = network.augment();
+        (
+            flows
+                .into_iter()
+                .filter(|&((from, to), _)| {
+                    owners[from] != usize::MAX
+                        && owners[to] != usize::MAX
+                        && owners[from] != owners[to]
+                })
+                .map(|((from, to), flow)| {
+                    (
+                        (
+                            self.vertices[owners[from]].clone(),
+                            self.vertices[owners[to]].clone(),
+                        ),
+                        flow,
+                    )
+                })
+                .collect(),
+            total,
+        )
+    }
+}
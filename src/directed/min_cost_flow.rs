@@ -0,0 +1,214 @@
+//! Compute a [minimum-cost flow](https://en.wikipedia.org/wiki/Minimum-cost_flow_problem)
+//! in a directed graph whose edges have a capacity and a cost per unit of flow.
+
+use indexmap::IndexSet;
+use num_traits::{Bounded, Signed, Zero};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Type alias for minimum-cost flow results: the flow going through every edge,
+/// the total flow, and its total cost.
+#[allow(clippy::upper_case_acronyms)]
+pub type MCFlows<N, C> = (Vec<((N, N), C)>, C, C);
+
+/// Compute a maximum flow of minimum cost going through a directed graph.
+///
+/// - `vertices` is the collection of vertices in the graph.
+/// - `source` is the source node (the origin of the flow).
+/// - `sink` is the sink node (the target of the flow).
+/// - `edges` is an iterator-like object describing the edges of the graph, as
+///   `((from, to), capacity, cost)` triples where `cost` is the cost of sending one
+///   unit of flow through the edge. Capacities must be positive, and costs may be
+///   negative as long as no cycle has a negative total cost. Several edges may link
+///   the same nodes.
+///
+/// The function returns the flow going through the edges, in the order they have
+/// been given and omitting edges without flow, along with the total flow and its
+/// total cost.
+///
+/// This function uses successive shortest paths: the flow is augmented along the
+/// cheapest paths of the residual graph, found by Dijkstra's algorithm using Johnson
+/// potentials. The type `C` must be signed as the algorithm goes through residual
+/// edges of negative costs.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::min_cost_max_flow;
+///
+/// // Two paths of capacity 2 are available, the one going through 'b' is cheaper.
+/// let edges = [(('s', 'a'), 2, 1), (('a', 't'), 2, 3), (('s', 'b'), 3, 1), (('b', 't'), 2, 1)];
+/// let (flows, total, cost) = min_cost_max_flow(&['s', 'a', 'b', 't'], &'s', &'t', edges);
+/// assert_eq!(total, 4);
+/// assert_eq!(cost, 12);
+/// assert_eq!(flows, vec![(('s', 'a'), 2), (('a', 't'), 2), (('s', 'b'), 2), (('b', 't'), 2)]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `source`, `sink`, or an edge end is not found in
+/// `vertices`, or if the graph contains a cycle of negative cost.
+pub fn min_cost_max_flow<N, C, IE>(vertices: &[N], source: &N, sink: &N, edges: IE) -> MCFlows<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IE: IntoIterator<Item = ((N, N), C, C)>,
+{
+    successive_shortest_paths(vertices, source, sink, edges, C::max_value())
+}
+
+/// Compute a flow of value `value` and of minimum cost going through a directed graph.
+///
+/// The arguments and the result are the same as the ones of [`min_cost_max_flow`].
+/// The function returns `None` if the maximum flow is lower than `value`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::min_cost_flow;
+///
+/// let edges = [(('s', 'a'), 2, 1), (('a', 't'), 2, 3), (('s', 'b'), 3, 1), (('b', 't'), 2, 1)];
+/// let vertices = ['s', 'a', 'b', 't'];
+/// assert_eq!(
+///     min_cost_flow(&vertices, &'s', &'t', edges, 3),
+///     Some((vec![(('s', 'a'), 1), (('a', 't'), 1), (('s', 'b'), 2), (('b', 't'), 2)], 3, 8))
+/// );
+/// assert_eq!(min_cost_flow(&vertices, &'s', &'t', edges, 5), None);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `source`, `sink`, or an edge end is not found in
+/// `vertices`, or if the graph contains a cycle of negative cost.
+pub fn min_cost_flow<N, C, IE>(
+    vertices: &[N],
+    source: &N,
+    sink: &N,
+    edges: IE,
+    value: C,
+) -> Option<MCFlows<N, C>>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IE: IntoIterator<Item = ((N, N), C, C)>,
+{
+    let result = successive_shortest_paths(vertices, source, sink, edges, value);
+    (result.1 == value).then_some(result)
+}
+
+fn successive_shortest_paths<N, C, IE>(
+    vertices: &[N],
+    source: &N,
+    sink: &N,
+    edges: IE,
+    limit: C,
+) -> MCFlows<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IE: IntoIterator<Item = ((N, N), C, C)>,
+{
+    let reverse = vertices.iter().collect::<IndexSet<_>>();
+    let size = vertices.len();
+    let source = reverse.get_index_of(source).unwrap();
+    let sink = reverse.get_index_of(sink).unwrap();
+    // Residual edges are stored by pairs: edge 2i goes in the direction of the i-th
+    // given edge, and edge 2i+1 in the reverse one.
+    let mut ends = Vec::new();
+    let mut residuals = Vec::new();
+    let mut costs = Vec::new();
+    let mut outgoing = vec![Vec::new(); size];
+    for ((from, to), capacity, cost) in edges {
+        let from = reverse.get_index_of(&from).unwrap();
+        let to = reverse.get_index_of(&to).unwrap();
+        outgoing[from].push(ends.len());
+        outgoing[to].push(ends.len() + 1);
+        ends.extend([(from, to), (to, from)]);
+        residuals.extend([capacity, C::zero()]);
+        costs.extend([cost, -cost]);
+    }
+    // Initial potentials, needed only if some costs are negative, are the costs of
+    // the cheapest paths starting anywhere, computed by Bellman-Ford algorithm.
+    let mut potentials = vec![C::zero(); size];
+    if costs.iter().step_by(2).any(|&c| c < Zero::zero()) {
+        for round in 0..=size {
+            let mut changed = false;
+            for e in (0..ends.len()).step_by(2) {
+                let (from, to) = ends[e];
+                if residuals[e] > Zero::zero() && potentials[from] + costs[e] < potentials[to] {
+                    potentials[to] = potentials[from] + costs[e];
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+            assert!(round < size, "graph contains a cycle of negative cost");
+        }
+    }
+    let mut total = C::zero();
+    let mut distances = vec![None; size];
+    let mut parents = vec![usize::MAX; size];
+    let mut to_see = BinaryHeap::new();
+    while source != sink && total < limit {
+        // Find the cheapest path from the source to the sink, using reduced costs
+        // which are never negative.
+        distances.fill(None);
+        distances[source] = Some(C::zero());
+        to_see.push(Reverse((C::zero(), source)));
+        while let Some(Reverse((distance, node))) = to_see.pop() {
+            if distances[node].map_or(false, |d| d < distance) {
+                continue;
+            }
+            for &e in &outgoing[node] {
+                let to = ends[e].1;
+                if residuals[e] > Zero::zero() {
+                    let new_distance = distance + costs[e] + potentials[node] - potentials[to];
+                    if distances[to].map_or(true, |d| new_distance < d) {
+                        distances[to] = Some(new_distance);
+                        parents[to] = e;
+                        to_see.push(Reverse((new_distance, to)));
+                    }
+                }
+            }
+        }
+        if distances[sink].is_none() {
+            break;
+        }
+        for (potential, distance) in potentials.iter_mut().zip(&distances) {
+            if let Some(distance) = *distance {
+                *potential = *potential + distance;
+            }
+        }
+        // Send as much flow as possible along the path.
+        let mut flow = limit - total;
+        let mut node = sink;
+        while node != source {
+            let e = parents[node];
+            if residuals[e] < flow {
+                flow = residuals[e];
+            }
+            node = ends[e].0;
+        }
+        let mut node = sink;
+        while node != source {
+            let e = parents[node];
+            residuals[e] = residuals[e] - flow;
+            residuals[e ^ 1] = residuals[e ^ 1] + flow;
+            node = ends[e].0;
+        }
+        total = total + flow;
+    }
+    let mut cost = C::zero();
+    let flows = (0..ends.len())
+        .step_by(2)
+        .filter(|&e| residuals[e + 1] > Zero::zero())
+        .map(|e| {
+            let (from, to) = ends[e];
+            cost = cost + residuals[e + 1] * costs[e];
+            ((vertices[from], vertices[to]), residuals[e + 1])
+        })
+        .collect();
+    (flows, total, cost)
+}
@@ -1,22 +1,34 @@
 //! Algorithms for directed graphs.
 
+pub mod arborescence;
 pub mod astar;
 pub mod bfs;
+pub mod circulation;
+pub mod dag;
 pub mod dfs;
 pub mod dijkstra;
+pub mod dinic;
 pub mod edmonds_karp;
+pub mod elementary_cycles;
+pub mod flow_decomposition;
+pub mod flow_network;
 pub mod fringe;
 pub mod idastar;
 pub mod iddfs;
+pub mod min_cost_flow;
+pub mod push_relabel;
 pub mod strongly_connected_components;
 pub mod topological_sort;
+pub mod transitive_closure;
+pub mod weighted_cycles;
 pub mod yen;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use rustc_hash::FxHasher;
 use std::hash::{BuildHasherDefault, Hash};
 
 type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
+pub(crate) type FxIndexSet<K> = IndexSet<K, BuildHasherDefault<FxHasher>>;
 
 #[allow(clippy::needless_collect)]
 fn reverse_path<N, V, F>(parents: &FxIndexMap<N, V>, mut parent: F, start: usize) -> Vec<N>
@@ -35,3 +47,26 @@ where
     // unfold iterator is not double-ended due to its iterative nature.
     path.into_iter().rev().cloned().collect()
 }
+
+/// Explore a finite graph from `nodes` and return the discovered nodes along with
+/// the successors of every node, expressed as indices into the set of nodes.
+pub(crate) fn index_graph<N, FN, IN>(
+    nodes: &[N],
+    mut successors: FN,
+) -> (FxIndexSet<N>, Vec<Vec<usize>>)
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut indices = nodes.iter().cloned().collect::<FxIndexSet<N>>();
+    let mut graph = Vec::with_capacity(indices.len());
+    while graph.len() < indices.len() {
+        let succs = successors(indices.get_index(graph.len()).unwrap())
+            .into_iter()
+            .map(|n| indices.insert_full(n).0)
+            .collect();
+        graph.push(succs);
+    }
+    (indices, graph)
+}
@@ -0,0 +1,317 @@
+//! Compute the maximum flow that can go through a directed graph using the
+//! [push-relabel algorithm](https://en.wikipedia.org/wiki/Push–relabel_maximum_flow_algorithm).
+//!
+//! As in the [`dinic`](super::dinic) module, the capacity and flow representations
+//! of the [`edmonds_karp`](super::edmonds_karp) module are reused: wrapping a
+//! [`DenseCapacity`] or a [`SparseCapacity`] into a [`PushRelabel`] structure only
+//! changes the way the flow is augmented.
+
+use num_traits::{Bounded, Signed, Zero};
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::mem;
+
+use super::edmonds_karp::{
+    edmonds_karp, Common, DenseCapacity, EKFlows, EdmondsKarp, SparseCapacity,
+};
+use crate::matrix::Matrix;
+
+/// Compute the maximum flow that can go through a directed graph using the
+/// [push-relabel algorithm](https://en.wikipedia.org/wiki/Push–relabel_maximum_flow_algorithm).
+///
+/// This function takes the same arguments and returns the same result as
+/// [`edmonds_karp`](super::edmonds_karp::edmonds_karp), but runs in O(V²√E) time
+/// instead of O(VE²). It is particularly efficient on dense graphs. `EK` is the
+/// underlying representation of capacities and flows, such as
+/// [`DenseCapacity`](super::edmonds_karp::DenseCapacity).
+///
+/// # Panics
+///
+/// This function panics if `source` or `sink` is not found in `vertices`.
+pub fn push_relabel<N, C, IC, EK>(vertices: &[N], source: &N, sink: &N, caps: IC) -> EKFlows<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IC: IntoIterator<Item = ((N, N), C)>,
+    EK: EdmondsKarp<C>,
+{
+    edmonds_karp::<N, C, IC, PushRelabel<EK>>(vertices, source, sink, caps)
+}
+
+/// Helper for the `push_relabel` function using an adjacency matrix for dense graphs.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::push_relabel_dense;
+///
+/// let caps = [(('s', 'a'), 3), (('s', 'b'), 2), (('a', 'b'), 1), (('a', 't'), 1), (('b', 't'), 4)];
+/// let (flows, total) = push_relabel_dense(&['s', 'a', 'b', 't'], &'s', &'t', caps);
+/// assert_eq!(total, 4);
+/// assert_eq!(flows.len(), 5);
+/// ```
+pub fn push_relabel_dense<N, C, IC>(vertices: &[N], source: &N, sink: &N, caps: IC) -> EKFlows<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IC: IntoIterator<Item = ((N, N), C)>,
+{
+    push_relabel::<N, C, IC, DenseCapacity<C>>(vertices, source, sink, caps)
+}
+
+/// Helper for the `push_relabel` function using adjacency maps for sparse graphs.
+pub fn push_relabel_sparse<N, C, IC>(
+    vertices: &[N],
+    source: &N,
+    sink: &N,
+    caps: IC,
+) -> EKFlows<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IC: IntoIterator<Item = ((N, N), C)>,
+{
+    push_relabel::<N, C, IC, SparseCapacity<C>>(vertices, source, sink, caps)
+}
+
+/// Capacity and flow data whose maximum flow is computed using the push-relabel
+/// algorithm.
+///
+/// `EK` is the underlying representation, such as [`DenseCapacity`] or
+/// [`SparseCapacity`]. All the methods of the [`EdmondsKarp`] trait are delegated
+/// to it, except [`EdmondsKarp::augment`] which discharges the active node with the
+/// highest label first, and uses the gap and global relabelling heuristics.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// let capacities = vec![0, 5, 2, 0,
+///                       0, 0, 2, 3,
+///                       0, 0, 0, 4,
+///                       0, 0, 0, 0];
+/// let mut network = PushRelabel::<DenseCapacity<i32>>::from_vec(0, 3, capacities);
+/// assert_eq!(network.augment().1, 7);
+/// network.set_capacity(2, 3, 3);
+/// assert_eq!(network.augment().1, 6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PushRelabel<EK> {
+    inner: EK,
+}
+
+impl<EK> PushRelabel<EK> {
+    /// Return the underlying representation.
+    pub fn into_inner(self) -> EK {
+        self.inner
+    }
+}
+
+impl<C, EK> EdmondsKarp<C> for PushRelabel<EK>
+where
+    C: Copy + Zero + Signed + Ord + Bounded,
+    EK: EdmondsKarp<C>,
+{
+    fn new(size: usize, source: usize, sink: usize) -> Self {
+        Self {
+            inner: EK::new(size, source, sink),
+        }
+    }
+
+    fn from_matrix(source: usize, sink: usize, capacities: Matrix<C>) -> Self {
+        Self {
+            inner: EK::from_matrix(source, sink, capacities),
+        }
+    }
+
+    fn common(&self) -> &Common<C> {
+        self.inner.common()
+    }
+
+    fn common_mut(&mut self) -> &mut Common<C> {
+        self.inner.common_mut()
+    }
+
+    fn residual_successors(&self, from: usize) -> Vec<(usize, C)> {
+        self.inner.residual_successors(from)
+    }
+
+    fn residual_capacity(&self, from: usize, to: usize) -> C {
+        self.inner.residual_capacity(from, to)
+    }
+
+    fn flow(&self, from: usize, to: usize) -> C {
+        self.inner.flow(from, to)
+    }
+
+    fn flows_from(&self, from: usize) -> Vec<usize> {
+        self.inner.flows_from(from)
+    }
+
+    fn flows(&self) -> Vec<((usize, usize), C)> {
+        self.inner.flows()
+    }
+
+    fn add_flow(&mut self, from: usize, to: usize, capacity: C) {
+        self.inner.add_flow(from, to, capacity);
+    }
+
+    fn add_residual_capacity(&mut self, from: usize, to: usize, capacity: C) {
+        self.inner.add_residual_capacity(from, to, capacity);
+    }
+
+    fn augment(&mut self) -> EKFlows<usize, C> {
+        let size = self.size();
+        let source = self.source();
+        let sink = self.sink();
+        if source == sink {
+            return (Vec::new(), self.total_capacity());
+        }
+        // Nodes linked by an edge in any direction, as residual capacities only
+        // change along existing edges.
+        let mut neighbours = vec![Vec::new(); size];
+        for node in 0..size {
+            for (successor, _) in self.residual_successors(node) {
+                neighbours[node].push(successor);
+                neighbours[successor].push(node);
+            }
+        }
+        for ns in &mut neighbours {
+            ns.sort_unstable();
+            ns.dedup();
+        }
+        // Start from the current flow, and saturate the edges leaving the source.
+        let mut excess = vec![C::zero(); size];
+        for (successor, residual) in self.residual_successors(source) {
+            self.add_flow(source, successor, residual);
+            excess[successor] = excess[successor] + residual;
+        }
+        let mut heights = vec![0; size];
+        let mut counts = vec![0; 2 * size + 1];
+        let mut active = vec![Vec::new(); 2 * size + 1];
+        let mut current = vec![0; size];
+        let mut highest = 0;
+        let mut relabels = size;
+        loop {
+            if relabels >= size {
+                global_relabel(self, &neighbours, &mut heights);
+                counts.fill(0);
+                active.iter_mut().for_each(Vec::clear);
+                current.fill(0);
+                for node in 0..size {
+                    counts[heights[node]] += 1;
+                    if node != source && node != sink && excess[node] > Zero::zero() {
+                        active[heights[node]].push(node);
+                    }
+                }
+                highest = 2 * size;
+                relabels = 0;
+            }
+            while highest > 0 && active[highest].is_empty() {
+                highest -= 1;
+            }
+            let node = match active[highest].pop() {
+                Some(node) => node,
+                None => break,
+            };
+            // Discharge the node by pushing its excess to lower neighbours, and
+            // relabel it when none of them can receive more.
+            while excess[node] > Zero::zero() {
+                if let Some(&n) = neighbours[node].get(current[node]) {
+                    let residual = self.residual_capacity(node, n);
+                    if residual > Zero::zero() && heights[node] == heights[n] + 1 {
+                        let pushed = if excess[node] < residual {
+                            excess[node]
+                        } else {
+                            residual
+                        };
+                        self.add_flow(node, n, pushed);
+                        if n != source && n != sink && excess[n] == Zero::zero() {
+                            active[heights[n]].push(n);
+                        }
+                        excess[n] = excess[n] + pushed;
+                        excess[node] = excess[node] - pushed;
+                    } else {
+                        current[node] += 1;
+                    }
+                    continue;
+                }
+                let old = heights[node];
+                let new = neighbours[node]
+                    .iter()
+                    .filter(|&&n| self.residual_capacity(node, n) > Zero::zero())
+                    .map(|&n| heights[n] + 1)
+                    .min()
+                    .unwrap()
+                    .min(2 * size);
+                counts[old] -= 1;
+                counts[new] += 1;
+                heights[node] = new;
+                current[node] = 0;
+                relabels += 1;
+                // If no node is left at the old height, nodes above it cannot reach
+                // the sink anymore and will only be able to send their excess back
+                // to the source.
+                if old < size && counts[old] == 0 {
+                    for n in 0..size {
+                        if heights[n] > old && heights[n] < size {
+                            counts[heights[n]] -= 1;
+                            counts[size + 1] += 1;
+                            heights[n] = size + 1;
+                            current[n] = 0;
+                        }
+                    }
+                    for h in old + 1..size {
+                        let moved = mem::take(&mut active[h]);
+                        active[size + 1].extend(moved);
+                    }
+                    highest = highest.max(size + 1);
+                }
+                if relabels >= size {
+                    active[heights[node]].push(node);
+                    break;
+                }
+            }
+            highest = highest.max(heights[node]);
+        }
+        let total = self.total_capacity();
+        self.set_total_capacity(total + excess[sink]);
+        if self.detailed_flows() {
+            (self.flows(), self.total_capacity())
+        } else {
+            (Vec::new(), self.total_capacity())
+        }
+    }
+}
+
+// Set the height of every node to its distance to the sink in the residual graph,
+// or, if it cannot reach the sink, to the number of nodes plus its distance to
+// the source.
+fn global_relabel<C, EK>(network: &EK, neighbours: &[Vec<usize>], heights: &mut [usize])
+where
+    C: Copy + Zero + Signed + Ord + Bounded,
+    EK: EdmondsKarp<C>,
+{
+    let size = heights.len();
+    heights.fill(usize::MAX);
+    heights[network.sink()] = 0;
+    heights[network.source()] = size;
+    let mut to_see = VecDeque::new();
+    for start in [network.sink(), network.source()] {
+        to_see.push_back(start);
+        while let Some(node) = to_see.pop_front() {
+            for &n in &neighbours[node] {
+                if heights[n] == usize::MAX && network.residual_capacity(n, node) > Zero::zero() {
+                    heights[n] = heights[node] + 1;
+                    to_see.push_back(n);
+                }
+            }
+        }
+    }
+    for height in heights.iter_mut() {
+        if *height == usize::MAX {
+            *height = 2 * size;
+        }
+    }
+}
@@ -3,16 +3,19 @@
 //!
 //! A [path-based strong component
 //! algorithm](https://en.wikipedia.org/wiki/Path-based_strong_component_algorithm)
-//! is used.
+//! is used. The graph is explored without recursion, so that large graphs do not
+//! exhaust the stack.
 
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
+use super::index_graph;
+
 struct Params<N, FN>
 where
     N: Hash + Eq,
 {
-    preorders: HashMap<N, Option<usize>>,
+    preorders: HashMap<N, usize>,
     c: usize,
     successors: FN,
     p: Vec<N>,
@@ -27,12 +30,9 @@ where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    fn new(nodes: &[N], successors: FN) -> Self {
+    fn new(successors: FN) -> Self {
         Self {
-            preorders: nodes
-                .iter()
-                .map(|n| (n.clone(), None))
-                .collect::<HashMap<N, Option<usize>>>(),
+            preorders: HashMap::new(),
             c: 0,
             successors,
             p: Vec::new(),
@@ -43,42 +43,58 @@ where
     }
 }
 
-fn recurse_onto<N, FN, IN>(v: &N, params: &mut Params<N, FN>)
+fn explore<N, FN, IN>(v: &N, params: &mut Params<N, FN>)
 where
     N: Clone + Hash + Eq,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    params.preorders.insert(v.clone(), Some(params.c));
-    params.c += 1;
-    params.s.push(v.clone());
-    params.p.push(v.clone());
-    for w in (params.successors)(v) {
-        if !params.scca.contains(&w) {
-            if let Some(pw) = params.preorders.get(&w).and_then(|w| *w) {
-                while params.preorders[&params.p[params.p.len() - 1]].unwrap() > pw {
-                    params.p.pop();
+    // Nodes being explored, along with their successors which remain to be visited.
+    let mut stack = vec![enter(v, params)];
+    while let Some((v, successors)) = stack.last_mut() {
+        if let Some(w) = successors.next() {
+            if !params.scca.contains(&w) {
+                if let Some(&pw) = params.preorders.get(&w) {
+                    while params.preorders[&params.p[params.p.len() - 1]] > pw {
+                        params.p.pop();
+                    }
+                } else {
+                    let entered = enter(&w, params);
+                    stack.push(entered);
                 }
-            } else {
-                recurse_onto(&w, params);
             }
+            continue;
         }
-    }
-    if params.p[params.p.len() - 1] == *v {
-        params.p.pop();
-        let mut component = Vec::new();
-        while let Some(node) = params.s.pop() {
-            component.push(node.clone());
-            params.scca.insert(node.clone());
-            params.preorders.remove(&node);
-            if node == *v {
-                break;
+        if params.p[params.p.len() - 1] == *v {
+            params.p.pop();
+            let mut component = Vec::new();
+            while let Some(node) = params.s.pop() {
+                component.push(node.clone());
+                params.scca.insert(node.clone());
+                params.preorders.remove(&node);
+                if node == *v {
+                    break;
+                }
             }
+            params.scc.push(component);
         }
-        params.scc.push(component);
+        stack.pop();
     }
 }
 
+fn enter<N, FN, IN>(v: &N, params: &mut Params<N, FN>) -> (N, IN::IntoIter)
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    params.preorders.insert(v.clone(), params.c);
+    params.c += 1;
+    params.s.push(v.clone());
+    params.p.push(v.clone());
+    (v.clone(), (params.successors)(v).into_iter())
+}
+
 /// Partition nodes reachable from a starting point into strongly connected components.
 ///
 /// - `start` is the node we want to explore the graph from.
@@ -92,8 +108,8 @@ where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut params = Params::new(&[], successors);
-    recurse_onto(start, &mut params);
+    let mut params = Params::new(successors);
+    explore(start, &mut params);
     params.scc
 }
 
@@ -127,9 +143,93 @@ where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut params = Params::new(nodes, successors);
-    while let Some(node) = params.preorders.keys().find(|_| true).cloned() {
-        recurse_onto(&node, &mut params);
+    let mut params = Params::new(successors);
+    for node in nodes {
+        if !params.scca.contains(node) {
+            explore(node, &mut params);
+        }
     }
     params.scc
 }
+
+/// Condensation of a directed graph, as returned by [`condensation`].
+#[derive(Clone, Debug)]
+pub struct Condensation<N> {
+    /// The strongly connected components of the graph, in topological order: edges
+    /// only go from a component to components with a greater index.
+    pub components: Vec<Vec<N>>,
+    /// The index of the component containing every node.
+    pub component_index: HashMap<N, usize>,
+    /// The successors of every component in the condensation graph, without duplicates.
+    pub successors: Vec<Vec<usize>>,
+}
+
+/// Compute the [condensation](https://en.wikipedia.org/wiki/Strongly_connected_component#Definitions)
+/// of a directed graph, that is the directed acyclic graph obtained by contracting every
+/// strongly connected component into a single node.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::condensation;
+///
+/// // 1 and 2 form a cycle, which leads to 3.
+/// let c = condensation(&[1, 2, 3], |&n| match n {
+///     1 => vec![2],
+///     2 => vec![1, 3],
+///     _ => vec![],
+/// });
+/// assert_eq!(c.components.len(), 2);
+/// assert_eq!(c.component_index[&1], c.component_index[&2]);
+/// assert_eq!(c.components[c.component_index[&3]], vec![3]);
+/// assert_eq!(c.successors[c.component_index[&1]], vec![c.component_index[&3]]);
+/// ```
+pub fn condensation<N, FN, IN>(nodes: &[N], successors: FN) -> Condensation<N>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_graph(nodes, successors);
+    let mut sccs = strongly_connected_components(&(0..nodes.len()).collect::<Vec<_>>(), |&n| {
+        graph[n].iter().copied()
+    });
+    // Components have been found in reverse topological order.
+    sccs.reverse();
+    let mut indices = vec![0; nodes.len()];
+    for (c, scc) in sccs.iter().enumerate() {
+        for &n in scc {
+            indices[n] = c;
+        }
+    }
+    let mut seen = vec![usize::MAX; sccs.len()];
+    let successors = sccs
+        .iter()
+        .enumerate()
+        .map(|(c, scc)| {
+            let mut succs = Vec::new();
+            for &n in scc {
+                for &s in &graph[n] {
+                    let d = indices[s];
+                    if d != c && seen[d] != c {
+                        seen[d] = c;
+                        succs.push(d);
+                    }
+                }
+            }
+            succs
+        })
+        .collect();
+    Condensation {
+        components: sccs
+            .into_iter()
+            .map(|scc| scc.into_iter().map(|n| nodes[n].clone()).collect())
+            .collect(),
+        component_index: nodes.into_iter().zip(indices).collect(),
+        successors,
+    }
+}
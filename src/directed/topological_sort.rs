@@ -1,9 +1,14 @@
 //! Find a topological order in a directed graph if one exists.
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use fixedbitset::FixedBitSet;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::hash::Hash;
+use std::iter::FusedIterator;
 use std::mem;
 
+use super::{index_graph, FxIndexSet};
+
 /// Find a topological order in a directed graph if one exists.
 ///
 /// - `roots` is a collection of nodes that ought to be explored.
@@ -16,6 +21,9 @@ use std::mem;
 /// [`strongly_connected_component`](super::strongly_connected_components::strongly_connected_component)
 /// function, or if only one of the loops is needed the [`bfs_loop`](super::bfs::bfs_loop) function
 /// can be used instead to identify one of the shortest loops involving this node.
+/// If the cycle which has been detected is needed, the
+/// [`topological_sort_with_cycle`](self::topological_sort_with_cycle) function can be used
+/// instead.
 ///
 /// # Examples
 ///
@@ -69,57 +77,88 @@ use std::mem;
 /// set.sort();
 /// assert_eq!(set, vec![7, 8, 9]);
 /// ```
-pub fn topological_sort<N, FN, IN>(roots: &[N], mut successors: FN) -> Result<Vec<N>, N>
+pub fn topological_sort<N, FN, IN>(roots: &[N], successors: FN) -> Result<Vec<N>, N>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut marked = HashSet::with_capacity(roots.len());
-    let mut temp = HashSet::new();
-    let mut sorted = VecDeque::with_capacity(roots.len());
-    let mut roots: HashSet<N> = roots.iter().cloned().collect::<HashSet<_>>();
-    while let Some(node) = roots.iter().next().cloned() {
-        temp.clear();
-        visit(
-            &node,
-            &mut successors,
-            &mut roots,
-            &mut marked,
-            &mut temp,
-            &mut sorted,
-        )?;
-    }
-    Ok(sorted.into_iter().collect())
+    topological_sort_with_cycle(roots, successors).map_err(|mut cycle| cycle.pop().unwrap())
 }
 
-fn visit<N, FN, IN>(
-    node: &N,
-    successors: &mut FN,
-    unmarked: &mut HashSet<N>,
-    marked: &mut HashSet<N>,
-    temp: &mut HashSet<N>,
-    sorted: &mut VecDeque<N>,
-) -> Result<(), N>
+/// Find a topological order in a directed graph if one exists, or a cycle otherwise.
+///
+/// - `roots` is a collection of nodes that ought to be explored.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `roots`.
+///
+/// This function works like [`topological_sort`](self::topological_sort), but
+/// returns `Err` with a full cycle instead of a single node belonging to it. As in
+/// [`bfs_loop`](super::bfs::bfs_loop), the first node of the cycle is repeated at
+/// the end.
+///
+/// The graph is explored without recursion, so that long chains of nodes do not
+/// exhaust the stack. The `successors` function is called at most once per node.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::topological_sort_with_cycle;
+///
+/// fn successors(node: &usize) -> Vec<usize> {
+///   match *node {
+///     n if n < 5 => vec![n + 1],
+///     5 => vec![3],
+///     _ => vec![],
+///   }
+/// }
+///
+/// assert_eq!(topological_sort_with_cycle(&[1], successors), Err(vec![3, 4, 5, 3]));
+/// ```
+pub fn topological_sort_with_cycle<N, FN, IN>(
+    roots: &[N],
+    mut successors: FN,
+) -> Result<Vec<N>, Vec<N>>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    unmarked.remove(node);
-    if marked.contains(node) {
-        return Ok(());
-    }
-    if temp.contains(node) {
-        return Err(node.clone());
-    }
-    temp.insert(node.clone());
-    for n in successors(node) {
-        visit(&n, successors, unmarked, marked, temp, sorted)?;
+    let mut marked = HashSet::with_capacity(roots.len());
+    let mut temp = HashSet::new();
+    let mut sorted = VecDeque::with_capacity(roots.len());
+    let mut roots: HashSet<N> = roots.iter().cloned().collect::<HashSet<_>>();
+    // Nodes being explored, along with their successors which remain to be visited.
+    let mut stack: Vec<(N, IN::IntoIter)> = Vec::new();
+    while let Some(root) = roots.iter().next().cloned() {
+        roots.remove(&root);
+        temp.insert(root.clone());
+        let succs = successors(&root).into_iter();
+        stack.push((root, succs));
+        while let Some((_, succs)) = stack.last_mut() {
+            if let Some(node) = succs.next() {
+                roots.remove(&node);
+                if marked.contains(&node) {
+                    continue;
+                }
+                if temp.contains(&node) {
+                    let start = stack.iter().position(|(n, _)| *n == node).unwrap();
+                    let mut cycle = stack.drain(start..).map(|(n, _)| n).collect::<Vec<_>>();
+                    cycle.push(node);
+                    return Err(cycle);
+                }
+                temp.insert(node.clone());
+                let succs = successors(&node).into_iter();
+                stack.push((node, succs));
+            } else {
+                let (node, _) = stack.pop().unwrap();
+                temp.remove(&node);
+                marked.insert(node.clone());
+                sorted.push_front(node);
+            }
+        }
     }
-    marked.insert(node.clone());
-    sorted.push_front(node.clone());
-    Ok(())
+    Ok(sorted.into_iter().collect())
 }
 
 /// Topologically sort a directed graph into groups of independent nodes.
@@ -213,3 +252,220 @@ where
     groups.push(prev_group);
     Ok(groups)
 }
+
+/// Find the topological order of a directed graph which favours the smallest nodes
+/// according to a comparison function.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+/// - `compare` is used to order nodes which are ready at the same time.
+///
+/// Unlike [`topological_sort`](self::topological_sort) whose result depends on the
+/// order in which the nodes are explored, this function is deterministic: at every
+/// step, the smallest node whose predecessors have all been placed is selected. Using
+/// `Ord::cmp` as the comparison function gives the lexicographically smallest
+/// topological order.
+///
+/// The function returns either `Ok` with the topological order, or `Err` with a
+/// (sorted, remaining) tuple containing the nodes which could be sorted and the nodes
+/// which could not because of cycles.
+///
+/// The current implementation uses [Kahn's
+/// algorithm](https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm)
+/// with a binary heap, and runs in O(|V| log |V| + |E|) time.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::topological_sort_by;
+///
+/// // 3 must come before 1, and 4 before 2.
+/// let successors = |&n: &u32| match n {
+///     3 => vec![1],
+///     4 => vec![2],
+///     _ => vec![],
+/// };
+/// assert_eq!(topological_sort_by(&[4, 3, 2, 1], successors, Ord::cmp), Ok(vec![3, 1, 4, 2]));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn topological_sort_by<N, FN, IN, FC>(
+    nodes: &[N],
+    successors: FN,
+    mut compare: FC,
+) -> Result<Vec<N>, (Vec<N>, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FC: FnMut(&N, &N) -> Ordering,
+{
+    let (nodes, graph) = index_graph(nodes, successors);
+    // Nodes are identified by their rank according to `compare` in the heap.
+    let mut by_rank = (0..nodes.len()).collect::<Vec<_>>();
+    by_rank.sort_by(|&a, &b| compare(&nodes[a], &nodes[b]));
+    let mut ranks = vec![0; nodes.len()];
+    for (rank, &n) in by_rank.iter().enumerate() {
+        ranks[n] = rank;
+    }
+    let mut num_preds = in_degrees(&graph);
+    let mut ready = (0..nodes.len())
+        .filter(|&n| num_preds[n] == 0)
+        .map(|n| Reverse(ranks[n]))
+        .collect::<BinaryHeap<_>>();
+    let mut sorted = Vec::with_capacity(nodes.len());
+    while let Some(Reverse(rank)) = ready.pop() {
+        let node = by_rank[rank];
+        sorted.push(nodes[node].clone());
+        for &succ in &graph[node] {
+            num_preds[succ] -= 1;
+            if num_preds[succ] == 0 {
+                ready.push(Reverse(ranks[succ]));
+            }
+        }
+    }
+    if sorted.len() == nodes.len() {
+        Ok(sorted)
+    } else {
+        let remaining = (0..nodes.len())
+            .filter(|&n| num_preds[n] > 0)
+            .map(|n| nodes[n].clone())
+            .collect();
+        Err((sorted, remaining))
+    }
+}
+
+/// Find the topological order of a directed graph which favours the nodes with the
+/// smallest keys.
+///
+/// This function works like [`topological_sort_by`](self::topological_sort_by), using
+/// `key` to compute the priority of every node.
+#[allow(clippy::type_complexity)]
+pub fn topological_sort_by_key<N, FN, IN, K, FK>(
+    nodes: &[N],
+    successors: FN,
+    mut key: FK,
+) -> Result<Vec<N>, (Vec<N>, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    K: Ord,
+    FK: FnMut(&N) -> K,
+{
+    topological_sort_by(nodes, successors, |a, b| key(a).cmp(&key(b)))
+}
+
+fn in_degrees(graph: &[Vec<usize>]) -> Vec<usize> {
+    let mut num_preds = vec![0; graph.len()];
+    for succs in graph {
+        for &succ in succs {
+            num_preds[succ] += 1;
+        }
+    }
+    num_preds
+}
+
+/// Enumerate all the topological orders of a directed graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// The orders are generated lazily using backtracking. As their number grows
+/// exponentially with the number of independent nodes, this is only usable on small
+/// graphs. If the graph contains a cycle, no order is generated.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::all_topological_sorts;
+///
+/// // 1 must come before 2 and 3.
+/// let orders = all_topological_sorts(&[1, 2, 3], |&n| if n == 1 { vec![2, 3] } else { vec![] });
+/// assert_eq!(orders.collect::<Vec<_>>(), vec![vec![1, 2, 3], vec![1, 3, 2]]);
+/// ```
+pub fn all_topological_sorts<N, FN, IN>(nodes: &[N], successors: FN) -> AllTopologicalSorts<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_graph(nodes, successors);
+    let num_preds = in_degrees(&graph);
+    AllTopologicalSorts {
+        placed: FixedBitSet::with_capacity(nodes.len()),
+        order: Vec::with_capacity(nodes.len()),
+        cursors: vec![0],
+        done: false,
+        nodes,
+        graph,
+        num_preds,
+    }
+}
+
+/// Struct returned by [`all_topological_sorts`](crate::directed::topological_sort::all_topological_sorts).
+pub struct AllTopologicalSorts<N> {
+    nodes: FxIndexSet<N>,
+    graph: Vec<Vec<usize>>,
+    num_preds: Vec<usize>,
+    placed: FixedBitSet,
+    order: Vec<usize>,
+    // For every position in the order being built, the next candidate node to try.
+    cursors: Vec<usize>,
+    done: bool,
+}
+
+impl<N> AllTopologicalSorts<N> {
+    fn place(&mut self, node: usize) {
+        for &succ in &self.graph[node] {
+            self.num_preds[succ] -= 1;
+        }
+        self.placed.insert(node);
+        self.order.push(node);
+        self.cursors.push(0);
+    }
+
+    // Remove the last placed node, return `false` if there was none.
+    fn unplace(&mut self) -> bool {
+        if let Some(node) = self.order.pop() {
+            for &succ in &self.graph[node] {
+                self.num_preds[succ] += 1;
+            }
+            self.placed.set(node, false);
+            self.cursors.pop();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<N> Iterator for AllTopologicalSorts<N>
+where
+    N: Clone,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.order.len() == self.nodes.len() {
+                let order = self.order.iter().map(|&n| self.nodes[n].clone()).collect();
+                self.done = !self.unplace();
+                return Some(order);
+            }
+            let cursor = self.cursors.last_mut().unwrap();
+            if let Some(node) =
+                (*cursor..self.nodes.len()).find(|&n| !self.placed[n] && self.num_preds[n] == 0)
+            {
+                *cursor = node + 1;
+                self.place(node);
+            } else {
+                self.done = !self.unplace();
+            }
+        }
+        None
+    }
+}
+
+impl<N> FusedIterator for AllTopologicalSorts<N> where N: Clone {}
@@ -0,0 +1,180 @@
+//! Compute the [transitive closure](https://en.wikipedia.org/wiki/Transitive_closure#In_graph_theory)
+//! and the [transitive reduction](https://en.wikipedia.org/wiki/Transitive_reduction)
+//! of a finite directed graph.
+//!
+//! Reachability information is stored as one bitset per strongly connected
+//! component, so that checking whether a node can be reached from another one
+//! is done in constant time.
+
+use fixedbitset::FixedBitSet;
+use std::cmp::Reverse;
+use std::hash::Hash;
+
+use super::strongly_connected_components::strongly_connected_components;
+use super::{index_graph, FxIndexSet};
+
+/// Reachability information between the nodes of a directed graph, as
+/// returned by [`transitive_closure`].
+#[derive(Clone, Debug)]
+pub struct TransitiveClosure<N> {
+    nodes: FxIndexSet<N>,
+    components: Vec<usize>,
+    reachable: Vec<FixedBitSet>,
+}
+
+impl<N> TransitiveClosure<N>
+where
+    N: Eq + Hash,
+{
+    /// Check whether `to` can be reached from `from` by following at least one
+    /// edge. In particular, a node reaches itself only if it belongs to a cycle.
+    ///
+    /// Nodes which are not part of the graph reach nothing and cannot be reached.
+    #[must_use]
+    pub fn reaches(&self, from: &N, to: &N) -> bool {
+        match (self.nodes.get_index_of(from), self.nodes.get_index_of(to)) {
+            (Some(from), Some(to)) => self.reachable[self.components[from]].contains(to),
+            _ => false,
+        }
+    }
+
+    /// Iterate over the nodes which can be reached from `from` by following at
+    /// least one edge.
+    pub fn reachable_from(&self, from: &N) -> impl Iterator<Item = &N> + '_ {
+        self.nodes
+            .get_index_of(from)
+            .into_iter()
+            .flat_map(move |from| {
+                self.reachable[self.components[from]]
+                    .ones()
+                    .map(move |to| &self.nodes[to])
+            })
+    }
+
+    /// Iterate over all the edges `(from, to)` of the transitive closure.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> + '_ {
+        self.nodes.iter().enumerate().flat_map(move |(from, n)| {
+            self.reachable[self.components[from]]
+                .ones()
+                .map(move |to| (n, &self.nodes[to]))
+        })
+    }
+}
+
+/// Compute the reachable set of every strongly connected component of a graph
+/// given as indices. The components are returned in reverse topological order,
+/// along with the component index of every node.
+fn closure(graph: &[Vec<usize>]) -> (Vec<Vec<usize>>, Vec<usize>, Vec<FixedBitSet>) {
+    let size = graph.len();
+    let sccs = strongly_connected_components(&(0..size).collect::<Vec<_>>(), |&n| {
+        graph[n].iter().copied()
+    });
+    let mut components = vec![0; size];
+    for (c, scc) in sccs.iter().enumerate() {
+        for &n in scc {
+            components[n] = c;
+        }
+    }
+    let mut reachable: Vec<FixedBitSet> = Vec::with_capacity(sccs.len());
+    for (c, scc) in sccs.iter().enumerate() {
+        let mut reach = FixedBitSet::with_capacity(size);
+        for &n in scc {
+            for &s in &graph[n] {
+                // The first time `s` becomes reachable, add everything reachable
+                // from its component (unless it is in this one).
+                if !reach.put(s) && components[s] != c {
+                    reach.union_with(&reachable[components[s]]);
+                }
+            }
+        }
+        reachable.push(reach);
+    }
+    (sccs, components, reachable)
+}
+
+/// Compute the transitive closure of a finite directed graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// The graph may contain cycles. The returned [`TransitiveClosure`] answers
+/// reachability queries in constant time, and uses memory proportional to the number
+/// of nodes multiplied by the number of strongly connected components.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::transitive_closure;
+///
+/// let closure = transitive_closure(&[1], |&n| if n < 5 { vec![n + 1] } else { vec![] });
+/// assert!(closure.reaches(&1, &5));
+/// assert!(!closure.reaches(&5, &1));
+/// assert!(!closure.reaches(&1, &1));
+/// assert_eq!(closure.reachable_from(&3).collect::<Vec<_>>(), vec![&4, &5]);
+/// ```
+pub fn transitive_closure<N, FN, IN>(nodes: &[N], successors: FN) -> TransitiveClosure<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_graph(nodes, successors);
+    let (_, components, reachable) = closure(&graph);
+    TransitiveClosure {
+        nodes,
+        components,
+        reachable,
+    }
+}
+
+/// Compute the transitive reduction of a finite directed acyclic graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// The function returns either `Ok` with the edges of the smallest graph having the
+/// same reachability relation as the original graph, or `Err` with a node belonging
+/// to a cycle, as the transitive reduction of a graph containing cycles is not unique.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::transitive_reduction;
+///
+/// // Every integer from 1 to 6 leads to its multiples up to 6.
+/// let mut edges = transitive_reduction(&[1], |&n| (2..=6 / n).map(move |m| n * m)).unwrap();
+/// edges.sort();
+/// assert_eq!(edges, vec![(1, 2), (1, 3), (1, 5), (2, 4), (2, 6), (3, 6)]);
+/// ```
+pub fn transitive_reduction<N, FN, IN>(nodes: &[N], successors: FN) -> Result<Vec<(N, N)>, N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_graph(nodes, successors);
+    let (sccs, components, reachable) = closure(&graph);
+    if let Some(c) = (0..sccs.len()).find(|&c| reachable[c].contains(sccs[c][0])) {
+        return Err(nodes[sccs[c][0]].clone());
+    }
+    let mut edges = Vec::new();
+    let mut covered = FixedBitSet::with_capacity(nodes.len());
+    for (from, succs) in graph.iter().enumerate() {
+        // Components are in reverse topological order. Looking at the successors
+        // in topological order ensures that a successor which can be reached
+        // through another one will have been covered first.
+        let mut succs = succs.clone();
+        succs.sort_unstable_by_key(|&s| Reverse(components[s]));
+        succs.dedup();
+        covered.clear();
+        for to in succs {
+            if !covered.contains(to) {
+                edges.push((nodes[from].clone(), nodes[to].clone()));
+                covered.union_with(&reachable[components[to]]);
+            }
+        }
+    }
+    Ok(edges)
+}
@@ -0,0 +1,210 @@
+//! Find remarkable cycles in a finite weighted directed graph: cycles with a
+//! negative total weight, and cycles with the minimum mean weight using
+//! [Karp's algorithm](https://doi.org/10.1016/0012-365X(78)90011-0).
+
+use num_traits::{FromPrimitive, Zero};
+use std::hash::Hash;
+use std::ops::{Mul, Sub};
+
+use super::{index_graph, FxIndexSet};
+
+type WeightedGraph<C> = Vec<Vec<(usize, C)>>;
+
+// Index the graph, keeping the weight of every edge alongside its target. Weights
+// are stored aside as `index_graph` calls `successors` once for every node, in
+// the order of their indices.
+fn index_weighted_graph<N, C, FN, IN>(
+    nodes: &[N],
+    mut successors: FN,
+) -> (FxIndexSet<N>, WeightedGraph<C>)
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut weights = Vec::new();
+    let (nodes, graph) = index_graph(nodes, |n| {
+        let (succs, costs): (Vec<_>, Vec<_>) = successors(n).into_iter().unzip();
+        weights.push(costs);
+        succs
+    });
+    let graph = graph
+        .into_iter()
+        .zip(weights)
+        .map(|(succs, costs)| succs.into_iter().zip(costs).collect())
+        .collect();
+    (nodes, graph)
+}
+
+/// Find a cycle with the minimum mean weight using [Karp's
+/// algorithm](https://doi.org/10.1016/0012-365X(78)90011-0).
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, along with the weight
+///   of the edge going from the node to the successor. Weights may be negative.
+///   Nodes that were not present in `nodes` may be returned as well.
+///
+/// The function returns `None` if the graph contains no cycle, or `Some` with a cycle
+/// and its total weight. As in [`bfs_loop`](super::bfs::bfs_loop), the first node of the
+/// cycle is repeated at its end: the mean weight of the cycle is its total weight divided
+/// by `cycle.len() - 1`.
+///
+/// The cycle mean is not computed directly, so that integer weights can be used without
+/// losing precision. This function runs in O(|V| × |E|) time, and uses O(|V|²) memory.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::min_mean_cycle;
+///
+/// // Cycle a -> b -> a has a mean of 3, and cycle b -> c -> d -> b a mean of 2.
+/// let successors = |&n: &char| match n {
+///     'a' => vec![('b', 2)],
+///     'b' => vec![('a', 4), ('c', 1)],
+///     'c' => vec![('d', 2)],
+///     _ => vec![('b', 3)],
+/// };
+/// let (cycle, weight) = min_mean_cycle(&['a'], successors).unwrap();
+/// assert_eq!(weight, 6);
+/// assert_eq!(cycle.len(), 4);
+/// ```
+pub fn min_mean_cycle<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C> + Mul<Output = C> + FromPrimitive,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (nodes, graph) = index_weighted_graph(nodes, successors);
+    let size = nodes.len();
+    // weights[k][v] is the minimum weight of a walk with exactly k edges ending at v,
+    // and parents[k][v] the node before v on such a walk.
+    let mut weights: Vec<Vec<Option<C>>> = vec![vec![Some(Zero::zero()); size]];
+    let mut parents = vec![vec![usize::MAX; size]];
+    for k in 1..=size {
+        let mut current = vec![None; size];
+        let mut current_parents = vec![usize::MAX; size];
+        for (from, succs) in graph.iter().enumerate() {
+            if let Some(w) = weights[k - 1][from] {
+                for &(to, weight) in succs {
+                    let new_weight = w + weight;
+                    if current[to].map_or(true, |c| new_weight < c) {
+                        current[to] = Some(new_weight);
+                        current_parents[to] = from;
+                    }
+                }
+            }
+        }
+        weights.push(current);
+        parents.push(current_parents);
+    }
+    // For every node, the maximum over k of (weights[size][v] - weights[k][v]) / (size - k)
+    // is computed as a fraction, and the minimum of those is kept.
+    let as_c = |n: usize| C::from_usize(n).unwrap();
+    let mut best: Option<(C, usize, usize)> = None;
+    for (v, wn) in weights[size].iter().enumerate() {
+        if let Some(wn) = *wn {
+            let worst = (0..size)
+                .filter_map(|k| weights[k][v].map(|wk| (wn - wk, size - k)))
+                .reduce(|(a, p), (b, q)| {
+                    if a * as_c(q) < b * as_c(p) {
+                        (b, q)
+                    } else {
+                        (a, p)
+                    }
+                })
+                .unwrap();
+            if best.map_or(true, |(a, p, _)| worst.0 * as_c(p) < a * as_c(worst.1)) {
+                best = Some((worst.0, worst.1, v));
+            }
+        }
+    }
+    let (_, _, mut v) = best?;
+    // Any cycle on the minimum weight walk with `size` edges ending at v has the minimum
+    // mean weight.
+    let mut walk = vec![v; size + 1];
+    for k in (1..=size).rev() {
+        v = parents[k][v];
+        walk[k - 1] = v;
+    }
+    let mut positions = vec![usize::MAX; size];
+    for (k, &v) in walk.iter().enumerate() {
+        if positions[v] != usize::MAX {
+            let start = positions[v];
+            let weight = weights[k][v].unwrap() - weights[start][v].unwrap();
+            let cycle = walk[start..=k].iter().map(|&n| nodes[n].clone()).collect();
+            return Some((cycle, weight));
+        }
+        positions[v] = k;
+    }
+    unreachable!("no cycle on the walk")
+}
+
+/// Find a cycle with a negative total weight using the [Bellman-Ford
+/// algorithm](https://en.wikipedia.org/wiki/Bellman–Ford_algorithm).
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, along with the weight
+///   of the edge going from the node to the successor. Nodes that were not present in
+///   `nodes` may be returned as well.
+///
+/// The function returns `None` if the graph contains no cycle with a negative weight, or
+/// `Some` with such a cycle. As in [`bfs_loop`](super::bfs::bfs_loop), the first node of
+/// the cycle is repeated at its end. It runs in O(|V| × |E|) time.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::find_negative_cycle;
+///
+/// let successors = |&n: &u32| match n {
+///     1 => vec![(2, 1)],
+///     2 => vec![(3, -2)],
+///     _ => vec![(1, 1), (2, 1)],
+/// };
+/// assert_eq!(find_negative_cycle(&[1], successors), Some(vec![3, 2, 3]));
+/// ```
+pub fn find_negative_cycle<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (nodes, graph) = index_weighted_graph(nodes, successors);
+    let size = nodes.len();
+    // Every node starts with a zero distance, as if they were all reachable from
+    // an additional source.
+    let mut distances = vec![C::zero(); size];
+    let mut parents = vec![usize::MAX; size];
+    let mut relaxed = None;
+    for _ in 0..size {
+        relaxed = None;
+        for (from, succs) in graph.iter().enumerate() {
+            for &(to, weight) in succs {
+                let new_distance = distances[from] + weight;
+                if new_distance < distances[to] {
+                    distances[to] = new_distance;
+                    parents[to] = from;
+                    relaxed = Some(to);
+                }
+            }
+        }
+        relaxed?;
+    }
+    // A node has been relaxed during the last pass: going back enough through the
+    // parents ensures that we are on a negative cycle.
+    let mut v = relaxed?;
+    for _ in 0..size {
+        v = parents[v];
+    }
+    let mut cycle = vec![nodes[v].clone()];
+    let mut n = parents[v];
+    while n != v {
+        cycle.push(nodes[n].clone());
+        n = parents[n];
+    }
+    cycle.push(nodes[v].clone());
+    cycle.reverse();
+    Some(cycle)
+}
@@ -0,0 +1,381 @@
+//! Keep track of elements partitioned into disjoint sets using a
+//! [union-find](https://en.wikipedia.org/wiki/Disjoint-set_data_structure)
+//! data structure.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::mem;
+
+use crate::directed::FxIndexSet;
+
+/// Union-find structure over elements with consecutive indices starting at 0.
+///
+/// Sets are merged by size, and paths are compressed by path halving, which makes
+/// every operation run in quasi-constant amortized time.
+///
+/// If the structure is built with [`UnionFind::with_rollback`], paths are not
+/// compressed and operations run in O(log n) time, but unions can be undone using
+/// [`UnionFind::snapshot`] and [`UnionFind::rollback`]. This can be used to
+/// implement offline dynamic connectivity algorithms.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::UnionFind;
+///
+/// let mut sets = UnionFind::new(5);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(3, 4));
+/// assert!(!sets.union(1, 0));
+/// assert!(sets.same_set(0, 1));
+/// assert!(!sets.same_set(1, 3));
+/// assert_eq!(sets.set_size(4), 2);
+/// assert_eq!(sets.sets(), vec![vec![0, 1], vec![2], vec![3, 4]]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    sets_count: usize,
+    // Roots which have been attached to another one, if unions can be rolled back.
+    history: Option<Vec<usize>>,
+}
+
+impl UnionFind {
+    /// Create a new structure with `len` elements, each one in its own set.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            sets_count: len,
+            history: None,
+        }
+    }
+
+    /// Create a new structure with `len` elements, each one in its own set,
+    /// whose unions can be rolled back.
+    #[must_use]
+    pub fn with_rollback(len: usize) -> Self {
+        Self {
+            history: Some(Vec::new()),
+            ..Self::new(len)
+        }
+    }
+
+    /// Number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Check whether there are no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    #[must_use]
+    pub fn sets_count(&self) -> usize {
+        self.sets_count
+    }
+
+    /// Add a new element in its own set, and return its index.
+    pub fn add(&mut self) -> usize {
+        let index = self.parents.len();
+        self.parents.push(index);
+        self.sizes.push(1);
+        self.sets_count += 1;
+        index
+    }
+
+    /// Return the representative of the set containing `element`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `element` is out of range.
+    pub fn find(&mut self, mut element: usize) -> usize {
+        if self.history.is_some() {
+            while self.parents[element] != element {
+                element = self.parents[element];
+            }
+        } else {
+            while self.parents[element] != element {
+                self.parents[element] = self.parents[self.parents[element]];
+                element = self.parents[element];
+            }
+        }
+        element
+    }
+
+    /// Merge the sets containing `a` and `b`. Return `false` if they were
+    /// already in the same set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `a` or `b` is out of range.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.sets_count -= 1;
+        if let Some(history) = &mut self.history {
+            history.push(b);
+        }
+        true
+    }
+
+    /// Check whether `a` and `b` belong to the same set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `a` or `b` is out of range.
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `element`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `element` is out of range.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Return the disjoint sets, ordered by their smallest element. Elements
+    /// are sorted within each set.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut indices = vec![usize::MAX; self.len()];
+        let mut sets = Vec::with_capacity(self.sets_count);
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if indices[root] == usize::MAX {
+                indices[root] = sets.len();
+                sets.push(Vec::with_capacity(self.sizes[root]));
+            }
+            sets[indices[root]].push(element);
+        }
+        sets
+    }
+
+    /// Return a snapshot of the current sets, to be given later to
+    /// [`UnionFind::rollback`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the structure has not been built
+    /// with [`UnionFind::with_rollback`].
+    #[must_use]
+    pub fn snapshot(&self) -> usize {
+        self.history
+            .as_ref()
+            .expect("union-find structure built without rollback support")
+            .len()
+    }
+
+    /// Undo all the unions done since `snapshot` has been taken. Elements added
+    /// in the meantime are kept, each one in its own set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the structure has not been built
+    /// with [`UnionFind::with_rollback`], or if unions older than
+    /// `snapshot` have already been rolled back.
+    pub fn rollback(&mut self, snapshot: usize) {
+        let history = self
+            .history
+            .as_mut()
+            .expect("union-find structure built without rollback support");
+        assert!(snapshot <= history.len(), "invalid snapshot");
+        for b in history.drain(snapshot..).rev() {
+            let a = self.parents[b];
+            self.sizes[a] -= self.sizes[b];
+            self.parents[b] = b;
+            self.sets_count += 1;
+        }
+    }
+}
+
+/// Union-find structure over arbitrary hashable elements.
+///
+/// Elements are added to the structure, each one in its own set, when they are
+/// first given to [`DisjointSet::insert`] or [`DisjointSet::union`]. Elements
+/// which have not been added yet are considered to be alone in their set.
+/// See [`UnionFind`] for the complexity of the operations.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::DisjointSet;
+///
+/// let mut sets = DisjointSet::new();
+/// sets.union(&"Paris", &"Lyon");
+/// sets.union(&"Berlin", &"Munich");
+/// sets.union(&"Lyon", &"Marseille");
+/// assert!(sets.same_set(&"Paris", &"Marseille"));
+/// assert!(!sets.same_set(&"Paris", &"Berlin"));
+/// assert_eq!(sets.set_size(&"Lyon"), 3);
+/// assert_eq!(sets.sets().len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DisjointSet<N> {
+    elements: FxIndexSet<N>,
+    sets: UnionFind,
+}
+
+impl<N> Default for DisjointSet<N> {
+    fn default() -> Self {
+        Self {
+            elements: FxIndexSet::default(),
+            sets: UnionFind::default(),
+        }
+    }
+}
+
+impl<N> DisjointSet<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Create a new empty structure.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new empty structure whose unions can be rolled back. See
+    /// [`UnionFind::with_rollback`] for details.
+    #[must_use]
+    pub fn with_rollback() -> Self {
+        Self {
+            elements: FxIndexSet::default(),
+            sets: UnionFind::with_rollback(0),
+        }
+    }
+
+    /// Number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Check whether there are no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    #[must_use]
+    pub fn sets_count(&self) -> usize {
+        self.sets.sets_count()
+    }
+
+    /// Add `element` in its own set if it is not present yet. Return `true`
+    /// if it has been added.
+    pub fn insert(&mut self, element: &N) -> bool {
+        self.index(element).1
+    }
+
+    fn index(&mut self, element: &N) -> (usize, bool) {
+        if let Some(index) = self.elements.get_index_of(element) {
+            (index, false)
+        } else {
+            self.elements.insert(element.clone());
+            (self.sets.add(), true)
+        }
+    }
+
+    /// Return the representative of the set containing `element`, or `None`
+    /// if `element` has not been added.
+    pub fn find(&mut self, element: &N) -> Option<&N> {
+        let index = self.elements.get_index_of(element)?;
+        let root = self.sets.find(index);
+        self.elements.get_index(root)
+    }
+
+    /// Merge the sets containing `a` and `b`, adding them first if needed.
+    /// Return `false` if they were already in the same set.
+    pub fn union(&mut self, a: &N, b: &N) -> bool {
+        let (a, _) = self.index(a);
+        let (b, _) = self.index(b);
+        self.sets.union(a, b)
+    }
+
+    /// Check whether `a` and `b` belong to the same set.
+    pub fn same_set(&mut self, a: &N, b: &N) -> bool {
+        match (self.elements.get_index_of(a), self.elements.get_index_of(b)) {
+            (Some(a), Some(b)) => self.sets.same_set(a, b),
+            _ => a == b,
+        }
+    }
+
+    /// Number of elements in the set containing `element`.
+    pub fn set_size(&mut self, element: &N) -> usize {
+        self.elements
+            .get_index_of(element)
+            .map_or(1, |index| self.sets.set_size(index))
+    }
+
+    /// Return the disjoint sets.
+    pub fn sets(&mut self) -> Vec<HashSet<N>> {
+        self.sets
+            .sets()
+            .into_iter()
+            .map(|set| set.into_iter().map(|i| self.elements[i].clone()).collect())
+            .collect()
+    }
+
+    /// Return a snapshot of the current sets, to be given later to
+    /// [`DisjointSet::rollback`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the structure has not been built
+    /// with [`DisjointSet::with_rollback`].
+    #[must_use]
+    pub fn snapshot(&self) -> usize {
+        self.sets.snapshot()
+    }
+
+    /// Undo all the unions done since `snapshot` has been taken. Elements added
+    /// in the meantime are kept, each one in its own set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the structure has not been built
+    /// with [`DisjointSet::with_rollback`], or if unions older than
+    /// `snapshot` have already been rolled back.
+    pub fn rollback(&mut self, snapshot: usize) {
+        self.sets.rollback(snapshot);
+    }
+}
+
+#[test]
+fn test_path_halving() {
+    let mut sets = UnionFind {
+        parents: vec![0, 0, 1, 2, 3, 4, 5, 6],
+        sizes: vec![8, 7, 6, 5, 4, 3, 2, 1],
+        sets_count: 1,
+        history: None,
+    };
+    assert_eq!(sets.find(7), 0);
+    assert_eq!(sets.parents, vec![0, 0, 1, 1, 3, 3, 5, 5]);
+    assert_eq!(sets.find(7), 0);
+    assert_eq!(sets.parents, vec![0, 0, 1, 0, 3, 3, 5, 3]);
+    assert_eq!(sets.find(7), 0);
+    assert_eq!(sets.parents, vec![0, 0, 1, 0, 3, 3, 5, 0]);
+    assert_eq!(sets.find(6), 0);
+    assert_eq!(sets.parents, vec![0, 0, 1, 0, 3, 3, 3, 0]);
+    assert_eq!(sets.find(6), 0);
+    assert_eq!(sets.parents, vec![0, 0, 1, 0, 3, 3, 0, 0]);
+}
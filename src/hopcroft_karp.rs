@@ -0,0 +1,250 @@
+//! Compute a maximum cardinality matching in an unweighted bipartite graph using the
+//! [Hopcroft-Karp algorithm](https://en.wikipedia.org/wiki/Hopcroft–Karp_algorithm),
+//! and deduce a minimum vertex cover and a maximum independent set from it using
+//! [Kőnig's theorem](https://en.wikipedia.org/wiki/Kőnig's_theorem_(graph_theory)).
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::directed::FxIndexSet;
+
+/// Maximum cardinality matching in a bipartite graph, as returned by
+/// [`hopcroft_karp`].
+#[derive(Clone, Debug)]
+pub struct BipartiteMatching<L, R> {
+    left: FxIndexSet<L>,
+    right: FxIndexSet<R>,
+    graph: Vec<Vec<usize>>,
+    left_mates: Vec<usize>,
+    right_mates: Vec<usize>,
+}
+
+/// Compute a maximum cardinality matching in an unweighted bipartite graph using the
+/// [Hopcroft-Karp algorithm](https://en.wikipedia.org/wiki/Hopcroft–Karp_algorithm).
+///
+/// - `left` is the collection of vertices of the first set.
+/// - `neighbours` is a function returning the vertices of the second set linked
+///   to a given vertex of the first set.
+///
+/// The vertices of the second set are the ones returned by `neighbours`, and
+/// the two sets may use different types. This algorithm executes in O(E√V) where
+/// E is the number of edges and V the number of vertices, and does not need a
+/// dense matrix of weights as [`kuhn_munkres`](crate::kuhn_munkres::kuhn_munkres).
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // Workers and the tasks they are able to perform.
+/// let skills = |worker: &&str| match *worker {
+///     "alice" => vec![1, 2],
+///     "bob" => vec![1],
+///     "carol" => vec![1, 3],
+///     "dave" => vec![3],
+///     _ => vec![],
+/// };
+/// let matching = hopcroft_karp(&["alice", "bob", "carol", "dave"], skills);
+/// assert_eq!(matching.len(), 3);
+/// assert_eq!(matching.left_mate(&"alice"), Some(&2));
+/// assert_eq!(matching.right_mate(&2), Some(&"alice"));
+///
+/// // Alice and the tasks 1 and 3 are involved in every possible assignment.
+/// assert_eq!(matching.vertex_cover(), (vec!["alice"], vec![1, 3]));
+/// assert_eq!(matching.independent_set(), (vec!["bob", "carol", "dave"], vec![2]));
+/// ```
+pub fn hopcroft_karp<L, R, FN, IN>(left: &[L], mut neighbours: FN) -> BipartiteMatching<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+    FN: FnMut(&L) -> IN,
+    IN: IntoIterator<Item = R>,
+{
+    let left = left.iter().cloned().collect::<FxIndexSet<_>>();
+    let mut right = FxIndexSet::default();
+    let graph = left
+        .iter()
+        .map(|l| {
+            let mut successors = neighbours(l)
+                .into_iter()
+                .map(|r| right.insert_full(r).0)
+                .collect::<Vec<_>>();
+            successors.sort_unstable();
+            successors.dedup();
+            successors
+        })
+        .collect::<Vec<_>>();
+    let mut left_mates = vec![usize::MAX; left.len()];
+    let mut right_mates = vec![usize::MAX; right.len()];
+    let mut depths = vec![usize::MAX; left.len()];
+    let mut to_see = VecDeque::new();
+    loop {
+        // Compute the depth of the left vertices in the alternating forest rooted at
+        // the unmatched left vertices, and the length of the shortest augmenting path.
+        for (l, depth) in depths.iter_mut().enumerate() {
+            *depth = if left_mates[l] == usize::MAX {
+                to_see.push_back(l);
+                0
+            } else {
+                usize::MAX
+            };
+        }
+        let mut shortest = usize::MAX;
+        while let Some(l) = to_see.pop_front() {
+            if depths[l] >= shortest {
+                continue;
+            }
+            for &r in &graph[l] {
+                let mate = right_mates[r];
+                if mate == usize::MAX {
+                    shortest = depths[l];
+                } else if depths[mate] == usize::MAX {
+                    depths[mate] = depths[l] + 1;
+                    to_see.push_back(mate);
+                }
+            }
+        }
+        if shortest == usize::MAX {
+            break;
+        }
+        // Augment the matching along a maximal set of vertex-disjoint shortest
+        // augmenting paths.
+        let mut next = vec![0; left.len()];
+        for root in 0..left.len() {
+            if left_mates[root] != usize::MAX || depths[root] != 0 {
+                continue;
+            }
+            let mut path = vec![root];
+            while let Some(&l) = path.last() {
+                if next[l] == graph[l].len() {
+                    depths[l] = usize::MAX;
+                    path.pop();
+                    continue;
+                }
+                let r = graph[l][next[l]];
+                next[l] += 1;
+                let mate = right_mates[r];
+                if mate == usize::MAX {
+                    if depths[l] == shortest {
+                        for &l in &path {
+                            let r = graph[l][next[l] - 1];
+                            left_mates[l] = r;
+                            right_mates[r] = l;
+                            depths[l] = usize::MAX;
+                        }
+                        break;
+                    }
+                } else if depths[mate] == depths[l] + 1 {
+                    path.push(mate);
+                }
+            }
+        }
+    }
+    BipartiteMatching {
+        left,
+        right,
+        graph,
+        left_mates,
+        right_mates,
+    }
+}
+
+impl<L, R> BipartiteMatching<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    /// Return the number of matched pairs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.left_mates.iter().filter(|&&r| r != usize::MAX).count()
+    }
+
+    /// Check whether the matching is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.left_mates.iter().all(|&r| r == usize::MAX)
+    }
+
+    /// Return the matched pairs, in the order of the vertices of the first set.
+    #[must_use]
+    pub fn pairs(&self) -> Vec<(L, R)> {
+        self.left_mates
+            .iter()
+            .enumerate()
+            .filter(|&(_, &r)| r != usize::MAX)
+            .map(|(l, &r)| (self.left[l].clone(), self.right[r].clone()))
+            .collect()
+    }
+
+    /// Return the vertex of the second set matched with `left`, if any.
+    #[must_use]
+    pub fn left_mate(&self, left: &L) -> Option<&R> {
+        let r = self.left_mates[self.left.get_index_of(left)?];
+        (r != usize::MAX).then(|| &self.right[r])
+    }
+
+    /// Return the vertex of the first set matched with `right`, if any.
+    #[must_use]
+    pub fn right_mate(&self, right: &R) -> Option<&L> {
+        let l = self.right_mates[self.right.get_index_of(right)?];
+        (l != usize::MAX).then(|| &self.left[l])
+    }
+
+    // Return the vertices of the first set whose reachability from the unmatched
+    // vertices of the first set through alternating paths is `reached`, and the
+    // vertices of the second set whose reachability is not `reached`.
+    fn split(&self, reached: bool) -> (Vec<L>, Vec<R>) {
+        let mut left_reached = vec![false; self.left.len()];
+        let mut right_reached = vec![false; self.right.len()];
+        let mut to_see = (0..self.left.len())
+            .filter(|&l| self.left_mates[l] == usize::MAX)
+            .collect::<Vec<_>>();
+        for &l in &to_see {
+            left_reached[l] = true;
+        }
+        while let Some(l) = to_see.pop() {
+            for &r in &self.graph[l] {
+                if !right_reached[r] {
+                    right_reached[r] = true;
+                    let mate = self.right_mates[r];
+                    if mate != usize::MAX && !left_reached[mate] {
+                        left_reached[mate] = true;
+                        to_see.push(mate);
+                    }
+                }
+            }
+        }
+        (
+            self.left
+                .iter()
+                .zip(left_reached)
+                .filter(|&(_, r)| r == reached)
+                .map(|(l, _)| l.clone())
+                .collect(),
+            self.right
+                .iter()
+                .zip(right_reached)
+                .filter(|&(_, r)| r != reached)
+                .map(|(r, _)| r.clone())
+                .collect(),
+        )
+    }
+
+    /// Return a minimum vertex cover, that is a smallest set of vertices touching
+    /// every edge, as vertices of the first and of the second set. Its size is the
+    /// size of the matching.
+    #[must_use]
+    pub fn vertex_cover(&self) -> (Vec<L>, Vec<R>) {
+        self.split(false)
+    }
+
+    /// Return a maximum independent set, that is a largest set of vertices no two
+    /// of which are linked by an edge, as vertices of the first and of the second
+    /// set. It is the complement of the [minimum vertex cover](Self::vertex_cover).
+    /// Only the vertices of the second set returned by `neighbours` are considered.
+    #[must_use]
+    pub fn independent_set(&self) -> (Vec<L>, Vec<R>) {
+        self.split(true)
+    }
+}
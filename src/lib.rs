@@ -7,7 +7,9 @@ pub use num_traits;
 
 pub mod cycle_detection;
 pub mod directed;
+pub mod disjoint_set;
 pub mod grid;
+pub mod hopcroft_karp;
 pub mod kuhn_munkres;
 pub mod matrix;
 pub mod undirected;
@@ -16,21 +18,44 @@ pub mod utils;
 /// Export all public functions and structures for an easy access.
 pub mod prelude {
     pub use crate::cycle_detection::*;
+    pub use crate::directed::arborescence::*;
     pub use crate::directed::astar::*;
     pub use crate::directed::bfs::*;
+    pub use crate::directed::circulation::*;
+    pub use crate::directed::dag::*;
     pub use crate::directed::dfs::*;
     pub use crate::directed::dijkstra::*;
+    pub use crate::directed::dinic::*;
     pub use crate::directed::edmonds_karp::*;
+    pub use crate::directed::elementary_cycles::*;
+    pub use crate::directed::flow_decomposition::*;
+    pub use crate::directed::flow_network::*;
     pub use crate::directed::fringe::*;
     pub use crate::directed::idastar::*;
     pub use crate::directed::iddfs::*;
+    pub use crate::directed::min_cost_flow::*;
+    pub use crate::directed::push_relabel::*;
     pub use crate::directed::strongly_connected_components::*;
     pub use crate::directed::topological_sort::*;
+    pub use crate::directed::transitive_closure::*;
+    pub use crate::directed::weighted_cycles::*;
     pub use crate::directed::yen::*;
+    pub use crate::disjoint_set::*;
     pub use crate::grid::*;
+    pub use crate::hopcroft_karp::*;
     pub use crate::kuhn_munkres::*;
     pub use crate::matrix::*;
+    pub use crate::undirected::biconnected_components::*;
+    pub use crate::undirected::bipartition::*;
+    pub use crate::undirected::blossom::*;
+    pub use crate::undirected::boruvka::*;
+    pub use crate::undirected::cliques::*;
+    pub use crate::undirected::colouring::*;
     pub use crate::undirected::connected_components::*;
+    pub use crate::undirected::gomory_hu::*;
     pub use crate::undirected::kruskal::*;
+    pub use crate::undirected::prim::*;
+    pub use crate::undirected::steiner_tree::*;
+    pub use crate::undirected::stoer_wagner::*;
     pub use crate::utils::*;
 }
@@ -0,0 +1,340 @@
+//! Find the articulation points, bridges, and 2-vertex- and 2-edge-connected
+//! components of an undirected graph.
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::directed::{index_graph, FxIndexSet};
+
+// Result of a depth-first search over the whole graph, using node indices.
+struct Analysis {
+    articulation_points: FixedBitSet,
+    bridges: Vec<(usize, usize)>,
+    biconnected_components: Vec<Vec<usize>>,
+    two_edge_connected_components: Vec<Vec<usize>>,
+}
+
+struct Frame {
+    node: usize,
+    parent: usize,
+    // The edge leading to the parent must be ignored only once, so that
+    // parallel edges are considered as a cycle.
+    skipped_parent: bool,
+    next_neighbour: usize,
+    children: usize,
+    vertex_stack_start: usize,
+}
+
+impl Frame {
+    fn new(node: usize, parent: usize, vertex_stack_start: usize) -> Self {
+        Self {
+            node,
+            parent,
+            skipped_parent: false,
+            next_neighbour: 0,
+            children: 0,
+            vertex_stack_start,
+        }
+    }
+}
+
+#[allow(clippy::similar_names)]
+fn analyze(graph: &[Vec<usize>]) -> Analysis {
+    let size = graph.len();
+    let mut preorders = vec![usize::MAX; size];
+    let mut lowlinks = vec![0; size];
+    let mut index = 0;
+    let mut analysis = Analysis {
+        articulation_points: FixedBitSet::with_capacity(size),
+        bridges: Vec::new(),
+        biconnected_components: Vec::new(),
+        two_edge_connected_components: Vec::new(),
+    };
+    let mut edge_stack = Vec::new();
+    let mut vertex_stack = Vec::new();
+    let mut seen = vec![usize::MAX; size];
+    for root in 0..size {
+        if preorders[root] != usize::MAX {
+            continue;
+        }
+        preorders[root] = index;
+        lowlinks[root] = index;
+        index += 1;
+        vertex_stack.push(root);
+        let mut stack = vec![Frame::new(root, usize::MAX, 0)];
+        while let Some(frame) = stack.last_mut() {
+            let v = frame.node;
+            if let Some(&w) = graph[v].get(frame.next_neighbour) {
+                frame.next_neighbour += 1;
+                if w == v {
+                    continue;
+                }
+                if w == frame.parent && !frame.skipped_parent {
+                    frame.skipped_parent = true;
+                    continue;
+                }
+                if preorders[w] == usize::MAX {
+                    frame.children += 1;
+                    preorders[w] = index;
+                    lowlinks[w] = index;
+                    index += 1;
+                    edge_stack.push((v, w));
+                    stack.push(Frame::new(w, v, vertex_stack.len()));
+                    vertex_stack.push(w);
+                } else if preorders[w] < preorders[v] {
+                    lowlinks[v] = lowlinks[v].min(preorders[w]);
+                    edge_stack.push((v, w));
+                }
+                continue;
+            }
+            let frame = stack.pop().unwrap();
+            let parent = frame.parent;
+            if parent == usize::MAX {
+                if frame.children > 1 {
+                    analysis.articulation_points.insert(v);
+                } else if frame.children == 0 {
+                    analysis.biconnected_components.push(vec![v]);
+                }
+                analysis
+                    .two_edge_connected_components
+                    .push(std::mem::take(&mut vertex_stack));
+                continue;
+            }
+            lowlinks[parent] = lowlinks[parent].min(lowlinks[v]);
+            if lowlinks[v] > preorders[parent] {
+                analysis.bridges.push((parent, v));
+                analysis
+                    .two_edge_connected_components
+                    .push(vertex_stack.split_off(frame.vertex_stack_start));
+            }
+            if lowlinks[v] >= preorders[parent] {
+                if stack.len() > 1 {
+                    analysis.articulation_points.insert(parent);
+                }
+                let component_index = analysis.biconnected_components.len();
+                let mut component = Vec::new();
+                while let Some(edge) = edge_stack.pop() {
+                    for n in [edge.0, edge.1] {
+                        if seen[n] != component_index {
+                            seen[n] = component_index;
+                            component.push(n);
+                        }
+                    }
+                    if edge == (parent, v) {
+                        break;
+                    }
+                }
+                analysis.biconnected_components.push(component);
+            }
+        }
+    }
+    analysis
+}
+
+// Explore the graph and make it symmetric. An edge between `a` and `b` is kept
+// as many times as it appears in the neighbours of `a` or in the ones of `b`,
+// whichever is the largest, so that parallel edges are preserved.
+fn index_multigraph<N, FN, IN>(starts: &[N], neighbours: FN) -> (FxIndexSet<N>, Vec<Vec<usize>>)
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, mut successors) = index_graph(starts, neighbours);
+    for succs in &mut successors {
+        succs.sort_unstable();
+    }
+    let mut graph = successors.clone();
+    for (n, succs) in successors.iter().enumerate() {
+        for (s, group) in &succs.iter().group_by(|&&s| s) {
+            if s == n {
+                continue;
+            }
+            let others = &successors[s];
+            let reverse = others.partition_point(|&m| m <= n) - others.partition_point(|&m| m < n);
+            for _ in reverse..group.count() {
+                graph[s].push(n);
+            }
+        }
+    }
+    (nodes, graph)
+}
+
+fn index_sets<N>(nodes: &FxIndexSet<N>, sets: Vec<Vec<usize>>) -> Vec<HashSet<N>>
+where
+    N: Eq + Hash + Clone,
+{
+    sets.into_iter()
+        .map(|set| set.into_iter().map(|n| nodes[n].clone()).collect())
+        .collect()
+}
+
+/// Find the articulation points of an undirected graph, that is the vertices
+/// whose removal increases the number of connected components.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric.
+///   Parallel edges are represented by repeating a neighbour.
+///
+/// The whole graph reachable from `starts` is explored using an iterative
+/// depth-first search in O(|V| + |E|) time.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::articulation_points;
+/// use std::collections::HashSet;
+///
+/// // Two triangles 1-2-3 and 3-4-5 sharing vertex 3.
+/// let neighbours = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![1, 3],
+///     3 => vec![1, 2, 4, 5],
+///     4 => vec![3, 5],
+///     _ => vec![3, 4],
+/// };
+/// assert_eq!(articulation_points(&[1], neighbours), HashSet::from([3]));
+/// ```
+pub fn articulation_points<N, FN, IN>(starts: &[N], neighbours: FN) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_multigraph(starts, neighbours);
+    analyze(&graph)
+        .articulation_points
+        .ones()
+        .map(|n| nodes[n].clone())
+        .collect()
+}
+
+/// Find the bridges of an undirected graph, that is the edges whose removal
+/// increases the number of connected components.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric.
+///   Parallel edges are represented by repeating a neighbour, and are never
+///   bridges.
+///
+/// Every bridge is returned once, as a pair of vertices in no particular order.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bridges;
+///
+/// // Triangle 1-2-3, with 4 hanging from 3.
+/// let neighbours = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![1, 3],
+///     3 => vec![1, 2, 4],
+///     _ => vec![3],
+/// };
+/// let bridges = bridges(&[1], neighbours);
+/// assert!(bridges == vec![(3, 4)] || bridges == vec![(4, 3)]);
+/// ```
+pub fn bridges<N, FN, IN>(starts: &[N], neighbours: FN) -> Vec<(N, N)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_multigraph(starts, neighbours);
+    analyze(&graph)
+        .bridges
+        .into_iter()
+        .map(|(a, b)| (nodes[a].clone(), nodes[b].clone()))
+        .collect()
+}
+
+/// Separate an undirected graph into its biconnected (2-vertex-connected)
+/// components, that is the maximal sets of vertices which stay connected
+/// after the removal of any single vertex.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric.
+///
+/// Every edge belongs to exactly one component, while articulation points belong
+/// to several ones. A bridge forms a component with two vertices, and an isolated
+/// vertex a component on its own.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::biconnected_components;
+/// use std::collections::HashSet;
+///
+/// // Two triangles 1-2-3 and 3-4-5 sharing vertex 3.
+/// let neighbours = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![1, 3],
+///     3 => vec![1, 2, 4, 5],
+///     4 => vec![3, 5],
+///     _ => vec![3, 4],
+/// };
+/// let components = biconnected_components(&[1], neighbours);
+/// assert_eq!(components.len(), 2);
+/// assert!(components.contains(&HashSet::from([1, 2, 3])));
+/// assert!(components.contains(&HashSet::from([3, 4, 5])));
+/// ```
+pub fn biconnected_components<N, FN, IN>(starts: &[N], neighbours: FN) -> Vec<HashSet<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_multigraph(starts, neighbours);
+    index_sets(&nodes, analyze(&graph).biconnected_components)
+}
+
+/// Separate an undirected graph into its 2-edge-connected components, that is
+/// the maximal sets of vertices which stay connected after the removal of any
+/// single edge.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric.
+///   Parallel edges are represented by repeating a neighbour.
+///
+/// The components are disjoint, and are the ones obtained by removing all the
+/// [`bridges`] from the graph.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::two_edge_connected_components;
+/// use std::collections::HashSet;
+///
+/// // Two triangles 1-2-3 and 3-4-5 sharing vertex 3, with 6 hanging from 5.
+/// let neighbours = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![1, 3],
+///     3 => vec![1, 2, 4, 5],
+///     4 => vec![3, 5],
+///     5 => vec![3, 4, 6],
+///     _ => vec![5],
+/// };
+/// let components = two_edge_connected_components(&[1], neighbours);
+/// assert_eq!(components.len(), 2);
+/// assert!(components.contains(&HashSet::from([1, 2, 3, 4, 5])));
+/// assert!(components.contains(&HashSet::from([6])));
+/// ```
+pub fn two_edge_connected_components<N, FN, IN>(starts: &[N], neighbours: FN) -> Vec<HashSet<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_multigraph(starts, neighbours);
+    index_sets(&nodes, analyze(&graph).two_edge_connected_components)
+}
@@ -0,0 +1,100 @@
+//! Split the vertices of a bipartite undirected graph into two sets.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use super::index_undirected_graph;
+
+/// Split the vertices of an undirected graph into two sets such that every
+/// edge links a vertex of the first set to a vertex of the second one.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric.
+///
+/// The function returns the two sets if the graph is bipartite. Within every
+/// connected component, the first vertex encountered is placed into the first
+/// set, so that isolated vertices always end up there.
+/// The two sets can for example be used as the rows and columns of the
+/// [`Weights`](crate::kuhn_munkres::Weights) given to
+/// [`kuhn_munkres`](crate::kuhn_munkres::kuhn_munkres).
+///
+/// If the graph is not bipartite, the function returns an odd cycle proving it.
+/// As in [`bfs_loop`](crate::directed::bfs::bfs_loop), the first node of the
+/// cycle is repeated at its end.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bipartition;
+///
+/// // A square 1-2-3-4 is bipartite.
+/// let square = |&n: &u32| vec![n % 4 + 1, (n + 2) % 4 + 1];
+/// assert_eq!(bipartition(&[1], square), Ok((vec![1, 3], vec![2, 4])));
+///
+/// // A triangle 1-2-3 is not.
+/// let triangle = |&n: &u32| vec![n % 3 + 1];
+/// let cycle = bipartition(&[1], triangle).unwrap_err();
+/// assert_eq!(cycle.len(), 4);
+/// assert_eq!(cycle.first(), cycle.last());
+/// ```
+pub fn bipartition<N, FN, IN>(starts: &[N], neighbours: FN) -> Result<(Vec<N>, Vec<N>), Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_undirected_graph(starts, neighbours);
+    let size = nodes.len();
+    let mut parents = vec![usize::MAX; size];
+    let mut depths = vec![usize::MAX; size];
+    let mut sides = (Vec::new(), Vec::new());
+    let mut to_see = VecDeque::new();
+    for root in 0..size {
+        if depths[root] != usize::MAX {
+            continue;
+        }
+        depths[root] = 0;
+        to_see.push_back(root);
+        while let Some(n) = to_see.pop_front() {
+            if depths[n] % 2 == 0 {
+                sides.0.push(nodes[n].clone());
+            } else {
+                sides.1.push(nodes[n].clone());
+            }
+            for &s in &graph[n] {
+                if depths[s] == usize::MAX {
+                    depths[s] = depths[n] + 1;
+                    parents[s] = n;
+                    to_see.push_back(s);
+                } else if depths[s] % 2 == depths[n] % 2 {
+                    return Err(odd_cycle(&parents, &depths, n, s)
+                        .into_iter()
+                        .map(|i| nodes[i].clone())
+                        .collect());
+                }
+            }
+        }
+    }
+    Ok(sides)
+}
+
+// Build the cycle made of the paths from `a` and `b` to their lowest
+// common ancestor in the breadth-first search tree, plus edge `a`-`b`.
+fn odd_cycle(parents: &[usize], depths: &[usize], mut a: usize, mut b: usize) -> Vec<usize> {
+    let mut from_a = vec![a];
+    let mut from_b = vec![b];
+    while a != b {
+        if depths[a] >= depths[b] {
+            a = parents[a];
+            from_a.push(a);
+        } else {
+            b = parents[b];
+            from_b.push(b);
+        }
+    }
+    from_a.reverse();
+    from_a.extend(from_b);
+    from_a
+}
@@ -0,0 +1,843 @@
+//! Find a maximum matching in a general undirected graph using
+//! [Edmonds' blossom algorithm](https://en.wikipedia.org/wiki/Blossom_algorithm),
+//! and a maximum weight matching using its primal-dual extension.
+
+use indexmap::IndexSet;
+use num_traits::Signed;
+use std::collections::{BTreeMap, VecDeque};
+use std::hash::Hash;
+
+use super::index_undirected_graph;
+
+/// Find a maximum cardinality matching in an undirected graph, that is a largest
+/// set of edges no two of which share a vertex, using
+/// [Edmonds' blossom algorithm](https://en.wikipedia.org/wiki/Blossom_algorithm).
+/// Contrary to [`hopcroft_karp`](crate::hopcroft_karp::hopcroft_karp), the graph
+/// does not need to be bipartite.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric.
+///
+/// The function returns the matched pairs of vertices. This algorithm executes
+/// in O(V³) where V is the number of vertices.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // Five people who can share a room with their friends: a triangle of
+/// // friends, one of which is also friend with a pair of friends.
+/// let friends = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![3],
+///     3 => vec![4],
+///     4 => vec![5],
+///     _ => vec![],
+/// };
+/// let pairs = maximum_matching(&[1, 2, 3, 4, 5], friends);
+/// assert_eq!(pairs.len(), 2);
+/// assert!(pairs.iter().all(|&(a, b)| friends(&a).contains(&b) || friends(&b).contains(&a)));
+/// ```
+pub fn maximum_matching<N, FN, IN>(starts: &[N], neighbours: FN) -> Vec<(N, N)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_undirected_graph(starts, neighbours);
+    let size = nodes.len();
+    let mut mates = vec![usize::MAX; size];
+    // Start with a greedy matching.
+    for n in 0..size {
+        if mates[n] == usize::MAX {
+            if let Some(&m) = graph[n].iter().find(|&&m| m != n && mates[m] == usize::MAX) {
+                mates[n] = m;
+                mates[m] = n;
+            }
+        }
+    }
+    // If no augmenting path starts from an unmatched vertex, none will once the
+    // matching has been augmented, so every vertex needs to be examined only once.
+    let mut search = AugmentingPathSearch::new(size);
+    for root in 0..size {
+        if mates[root] == usize::MAX {
+            search.augment(&graph, &mut mates, root);
+        }
+    }
+    (0..size)
+        .filter(|&n| mates[n] != usize::MAX && n < mates[n])
+        .map(|n| (nodes[n].clone(), nodes[mates[n]].clone()))
+        .collect()
+}
+
+struct AugmentingPathSearch {
+    // Base of the blossom every vertex belongs to.
+    bases: Vec<usize>,
+    // Predecessor of the odd vertices in the alternating tree.
+    parents: Vec<usize>,
+    // Even vertices of the alternating tree.
+    even: Vec<bool>,
+    in_blossom: Vec<bool>,
+    in_path: Vec<bool>,
+    to_see: VecDeque<usize>,
+}
+
+impl AugmentingPathSearch {
+    fn new(size: usize) -> Self {
+        Self {
+            bases: vec![0; size],
+            parents: vec![0; size],
+            even: vec![false; size],
+            in_blossom: vec![false; size],
+            in_path: vec![false; size],
+            to_see: VecDeque::new(),
+        }
+    }
+
+    // Look for an augmenting path starting from `root` by growing an alternating
+    // tree, contracting the blossoms on the way, and augment the matching along it.
+    fn augment(&mut self, graph: &[Vec<usize>], mates: &mut [usize], root: usize) {
+        for n in 0..graph.len() {
+            self.bases[n] = n;
+            self.parents[n] = usize::MAX;
+            self.even[n] = false;
+        }
+        self.even[root] = true;
+        self.to_see.clear();
+        self.to_see.push_back(root);
+        while let Some(n) = self.to_see.pop_front() {
+            for &m in &graph[n] {
+                if self.bases[n] == self.bases[m] || mates[n] == m {
+                    continue;
+                }
+                if m == root || (mates[m] != usize::MAX && self.parents[mates[m]] != usize::MAX) {
+                    // `n` and `m` are both even: contract the blossom they close.
+                    let base = self.common_ancestor(mates, n, m);
+                    self.in_blossom.fill(false);
+                    self.mark_path(mates, n, base, m);
+                    self.mark_path(mates, m, base, n);
+                    for v in 0..graph.len() {
+                        if self.in_blossom[self.bases[v]] {
+                            self.bases[v] = base;
+                            if !self.even[v] {
+                                self.even[v] = true;
+                                self.to_see.push_back(v);
+                            }
+                        }
+                    }
+                } else if self.parents[m] == usize::MAX {
+                    self.parents[m] = n;
+                    if mates[m] == usize::MAX {
+                        // Flip the edges along the augmenting path ending at `m`.
+                        let mut m = m;
+                        while m != usize::MAX {
+                            let n = self.parents[m];
+                            let next = mates[n];
+                            mates[m] = n;
+                            mates[n] = m;
+                            m = next;
+                        }
+                        return;
+                    }
+                    self.even[mates[m]] = true;
+                    self.to_see.push_back(mates[m]);
+                }
+            }
+        }
+    }
+
+    // Find the base of the closest blossom containing a common ancestor of `a`
+    // and `b` in the alternating tree.
+    fn common_ancestor(&mut self, mates: &[usize], mut a: usize, mut b: usize) -> usize {
+        self.in_path.fill(false);
+        loop {
+            a = self.bases[a];
+            self.in_path[a] = true;
+            if mates[a] == usize::MAX {
+                break;
+            }
+            a = self.parents[mates[a]];
+        }
+        loop {
+            b = self.bases[b];
+            if self.in_path[b] {
+                return b;
+            }
+            b = self.parents[mates[b]];
+        }
+    }
+
+    // Mark the blossoms on the path going from `n` to `base`, and make the
+    // odd vertices on it point towards `child` so that an augmenting path going
+    // through the blossom can be followed in both directions.
+    fn mark_path(&mut self, mates: &[usize], mut n: usize, base: usize, mut child: usize) {
+        while self.bases[n] != base {
+            self.in_blossom[self.bases[n]] = true;
+            self.in_blossom[self.bases[mates[n]]] = true;
+            self.parents[n] = child;
+            child = mates[n];
+            n = self.parents[mates[n]];
+        }
+    }
+}
+
+/// Find a maximum weight matching in an undirected graph, that is a set of edges
+/// no two of which share a vertex and whose total weight is maximal, using the
+/// primal-dual extension of
+/// [Edmonds' blossom algorithm](https://en.wikipedia.org/wiki/Blossom_algorithm).
+/// From a collection of weighted edges, return the matched pairs and their total
+/// weight.
+///
+/// Weights must be integers. Edges with a negative weight are never matched, only
+/// the heaviest edge is considered if several edges link the same vertices, and
+/// loops are ignored. This algorithm executes in O(V³) where V is the number of
+/// vertices. See [`maximum_weight_maximum_matching`] to first maximize the number
+/// of matched pairs.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // Tandem partners and how much they would learn from each other.
+/// let edges = [("ann", "bob", 6), ("bob", "cat", 8), ("cat", "dan", 5), ("ann", "dan", 1)];
+/// let (pairs, weight) = maximum_weight_matching(&edges);
+/// assert_eq!(weight, 11);
+/// assert_eq!(pairs, vec![(&"ann", &"bob"), (&"cat", &"dan")]);
+///
+/// // A single heavy pair is better.
+/// let edges = [("ann", "bob", 6), ("bob", "cat", 12), ("cat", "dan", 5)];
+/// assert_eq!(maximum_weight_matching(&edges), (vec![(&"bob", &"cat")], 12));
+/// ```
+pub fn maximum_weight_matching<N, C>(edges: &[(N, N, C)]) -> (Vec<(&N, &N)>, C)
+where
+    N: Hash + Eq,
+    C: Signed + Ord + Copy,
+{
+    weighted_matching(edges, false)
+}
+
+/// Find a maximum weight matching amongst the maximum cardinality matchings of an
+/// undirected graph, that is a largest set of edges no two of which share a vertex,
+/// and whose total weight is maximal amongst those sets. From a collection of
+/// weighted edges, return the matched pairs and their total weight.
+///
+/// Weights must be integers and may be negative, only the heaviest edge is
+/// considered if several edges link the same vertices, and loops are ignored.
+/// This algorithm executes in O(V³) where V is the number of vertices.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// let edges = [("ann", "bob", 6), ("bob", "cat", 12), ("cat", "dan", 5)];
+/// assert_eq!(
+///     maximum_weight_maximum_matching(&edges),
+///     (vec![(&"ann", &"bob"), (&"cat", &"dan")], 11)
+/// );
+/// ```
+pub fn maximum_weight_maximum_matching<N, C>(edges: &[(N, N, C)]) -> (Vec<(&N, &N)>, C)
+where
+    N: Hash + Eq,
+    C: Signed + Ord + Copy,
+{
+    weighted_matching(edges, true)
+}
+
+fn weighted_matching<N, C>(edges: &[(N, N, C)], max_cardinality: bool) -> (Vec<(&N, &N)>, C)
+where
+    N: Hash + Eq,
+    C: Signed + Ord + Copy,
+{
+    let mut nodes = IndexSet::new();
+    let mut weights = BTreeMap::new();
+    for (a, b, w) in edges {
+        let ia = nodes.insert_full(a).0;
+        let ib = nodes.insert_full(b).0;
+        if ia != ib {
+            let weight = weights.entry((ia.min(ib), ia.max(ib))).or_insert(*w);
+            *weight = (*weight).max(*w);
+        }
+    }
+    let edges = weights
+        .into_iter()
+        .map(|((a, b), w)| (a, b, w))
+        .collect::<Vec<_>>();
+    let mates = WeightedMatching::new(nodes.len(), edges, max_cardinality).solve();
+    let mut total = C::zero();
+    let pairs = mates
+        .into_iter()
+        .enumerate()
+        .filter(|&(a, mate)| mate.map_or(false, |(b, _)| a < b))
+        .map(|(a, mate)| {
+            let (b, w) = mate.unwrap();
+            total = total + w;
+            (
+                <&N>::clone(nodes.get_index(a).unwrap()),
+                <&N>::clone(nodes.get_index(b).unwrap()),
+            )
+        })
+        .collect();
+    (pairs, total)
+}
+
+// Index in a list seen as circular, with negative indices going backwards.
+fn circular(list: &[usize], index: isize) -> usize {
+    list[index.rem_euclid(list.len() as isize) as usize]
+}
+
+// Primal-dual maximum weight matching, following "Efficient Algorithms for Finding
+// Maximum Matching in Graphs" by Zvi Galil (1986). Vertices are numbered from 0 to
+// n-1, and blossoms from n to 2n-1. Edge `k` has two endpoints `2k` and `2k+1`,
+// which designate respectively its first and its second vertex.
+struct WeightedMatching<C> {
+    size: usize,
+    edges: Vec<(usize, usize, C)>,
+    max_cardinality: bool,
+    // Endpoints of the edges leaving every vertex, designating the other vertex.
+    neighbour_ends: Vec<Vec<usize>>,
+    // Endpoint designating the mate of every vertex, if any.
+    mates: Vec<usize>,
+    // Label of every vertex and top-level blossom: 0 for none, 1 for S (even),
+    // 2 for T (odd), with 4 added temporarily to mark visited blossoms.
+    labels: Vec<u8>,
+    // Endpoint through which a label has been given, if any.
+    label_ends: Vec<usize>,
+    // Top-level blossom containing every vertex.
+    in_blossom: Vec<usize>,
+    // Blossom immediately containing every vertex or blossom, if any.
+    parents: Vec<usize>,
+    // Sub-blossoms of every blossom, starting with the one containing the base.
+    children: Vec<Vec<usize>>,
+    // Endpoints of the edges linking consecutive sub-blossoms of every blossom.
+    child_ends: Vec<Vec<usize>>,
+    // Base vertex of every blossom, if any.
+    bases: Vec<usize>,
+    // Edge of least slack linking every vertex or S-blossom to another S-blossom.
+    best_edges: Vec<usize>,
+    // Edges of least slack linking every S-blossom to the other S-blossoms.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    // Dual variables of the vertices, counted twice, and of the blossoms.
+    duals: Vec<C>,
+    // Edges with a zero slack.
+    allowed: Vec<bool>,
+    to_see: Vec<usize>,
+}
+
+impl<C> WeightedMatching<C>
+where
+    C: Signed + Ord + Copy,
+{
+    fn new(size: usize, edges: Vec<(usize, usize, C)>, max_cardinality: bool) -> Self {
+        let mut neighbour_ends = vec![Vec::new(); size];
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            neighbour_ends[i].push(2 * k + 1);
+            neighbour_ends[j].push(2 * k);
+        }
+        let max_weight = edges
+            .iter()
+            .map(|&(_, _, w)| w)
+            .max()
+            .unwrap_or_else(C::zero)
+            .max(C::zero());
+        let mut duals = vec![max_weight; size];
+        duals.resize(2 * size, C::zero());
+        Self {
+            size,
+            max_cardinality,
+            neighbour_ends,
+            mates: vec![usize::MAX; size],
+            labels: vec![0; 2 * size],
+            label_ends: vec![usize::MAX; 2 * size],
+            in_blossom: (0..size).collect(),
+            parents: vec![usize::MAX; 2 * size],
+            children: vec![Vec::new(); 2 * size],
+            child_ends: vec![Vec::new(); 2 * size],
+            bases: (0..size)
+                .chain(std::iter::repeat(usize::MAX).take(size))
+                .collect(),
+            best_edges: vec![usize::MAX; 2 * size],
+            blossom_best_edges: vec![None; 2 * size],
+            unused_blossoms: (size..2 * size).rev().collect(),
+            duals,
+            allowed: vec![false; edges.len()],
+            to_see: Vec::new(),
+            edges,
+        }
+    }
+
+    fn endpoint(&self, p: usize) -> usize {
+        let (i, j, _) = self.edges[p / 2];
+        if p % 2 == 0 {
+            i
+        } else {
+            j
+        }
+    }
+
+    fn slack(&self, k: usize) -> C {
+        let (i, j, w) = self.edges[k];
+        self.duals[i] + self.duals[j] - w - w
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut to_see = vec![b];
+        while let Some(b) = to_see.pop() {
+            if b < self.size {
+                leaves.push(b);
+            } else {
+                to_see.extend(self.children[b].iter().rev());
+            }
+        }
+        leaves
+    }
+
+    // Label the top-level blossom containing `w`, reached through endpoint `p`,
+    // and label the mate of its base if it becomes a T-blossom.
+    fn assign_label(&mut self, w: usize, label: u8, p: usize) {
+        let b = self.in_blossom[w];
+        self.labels[w] = label;
+        self.labels[b] = label;
+        self.label_ends[w] = p;
+        self.label_ends[b] = p;
+        self.best_edges[w] = usize::MAX;
+        self.best_edges[b] = usize::MAX;
+        if label == 1 {
+            let leaves = self.leaves(b);
+            self.to_see.extend(leaves);
+        } else {
+            let mate = self.mates[self.bases[b]];
+            self.assign_label(self.endpoint(mate), 1, mate ^ 1);
+        }
+    }
+
+    // Trace back from S-vertices `v` and `w` to discover either a new blossom, whose
+    // base is returned, or an augmenting path.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> Option<usize> {
+        let mut path = Vec::new();
+        let mut base = None;
+        while v != usize::MAX {
+            let b = self.in_blossom[v];
+            if self.labels[b] & 4 != 0 {
+                base = Some(self.bases[b]);
+                break;
+            }
+            path.push(b);
+            self.labels[b] = 5;
+            if self.label_ends[b] == usize::MAX {
+                v = usize::MAX;
+            } else {
+                let t = self.in_blossom[self.endpoint(self.label_ends[b])];
+                v = self.endpoint(self.label_ends[t]);
+            }
+            if w != usize::MAX {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.labels[b] = 1;
+        }
+        base
+    }
+
+    // Build a new blossom with the given base, closed by edge `k` linking two
+    // S-vertices.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self.unused_blossoms.pop().unwrap();
+        self.bases[b] = base;
+        self.parents[b] = usize::MAX;
+        self.parents[bb] = b;
+        let mut path = Vec::new();
+        let mut ends = Vec::new();
+        while bv != bb {
+            self.parents[bv] = b;
+            path.push(bv);
+            ends.push(self.label_ends[bv]);
+            bv = self.in_blossom[self.endpoint(self.label_ends[bv])];
+        }
+        path.push(bb);
+        path.reverse();
+        ends.reverse();
+        ends.push(2 * k);
+        while bw != bb {
+            self.parents[bw] = b;
+            path.push(bw);
+            ends.push(self.label_ends[bw] ^ 1);
+            bw = self.in_blossom[self.endpoint(self.label_ends[bw])];
+        }
+        self.labels[b] = 1;
+        self.label_ends[b] = self.label_ends[bb];
+        self.duals[b] = C::zero();
+        self.children[b] = path.clone();
+        self.child_ends[b] = ends;
+        for v in self.leaves(b) {
+            if self.labels[self.in_blossom[v]] == 2 {
+                // T-vertices become S-vertices.
+                self.to_see.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+        // Compute the edges of least slack to the other S-blossoms.
+        let mut best_to = vec![usize::MAX; 2 * self.size];
+        for bv in path {
+            let candidates = match self.blossom_best_edges[bv].take() {
+                Some(edges) => edges,
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .flat_map(|v| self.neighbour_ends[v].iter().map(|p| p / 2))
+                    .collect(),
+            };
+            for k in candidates {
+                let (i, j, _) = self.edges[k];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.labels[bj] == 1
+                    && (best_to[bj] == usize::MAX || self.slack(k) < self.slack(best_to[bj]))
+                {
+                    best_to[bj] = k;
+                }
+            }
+            self.best_edges[bv] = usize::MAX;
+        }
+        let best = best_to
+            .into_iter()
+            .filter(|&k| k != usize::MAX)
+            .collect::<Vec<_>>();
+        self.best_edges[b] = best
+            .iter()
+            .copied()
+            .min_by_key(|&k| self.slack(k))
+            .unwrap_or(usize::MAX);
+        self.blossom_best_edges[b] = Some(best);
+    }
+
+    // Expand blossom `b`, relabelling its sub-blossoms if this happens in the
+    // middle of a stage.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for s in self.children[b].clone() {
+            self.parents[s] = usize::MAX;
+            if s < self.size {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.duals[s] == C::zero() {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+        if !end_stage && self.labels[b] == 2 {
+            // Relabel the sub-blossoms on the even-length path going from the
+            // entry child to the base, and the ones reachable from outside.
+            let children = self.children[b].clone();
+            let ends = self.child_ends[b].clone();
+            let entry = self.in_blossom[self.endpoint(self.label_ends[b] ^ 1)];
+            let mut j = children.iter().position(|&c| c == entry).unwrap() as isize;
+            let (step, trick) = if j & 1 == 1 {
+                j -= children.len() as isize;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let mut p = self.label_ends[b];
+            while j != 0 {
+                let end = circular(&ends, j - trick as isize);
+                let (q, r) = (self.endpoint(p ^ 1), self.endpoint(end ^ trick ^ 1));
+                self.labels[q] = 0;
+                self.labels[r] = 0;
+                self.assign_label(q, 2, p);
+                self.allowed[end / 2] = true;
+                j += step;
+                p = circular(&ends, j - trick as isize) ^ trick;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+            let bv = circular(&children, j);
+            let q = self.endpoint(p ^ 1);
+            self.labels[q] = 2;
+            self.labels[bv] = 2;
+            self.label_ends[q] = p;
+            self.label_ends[bv] = p;
+            self.best_edges[bv] = usize::MAX;
+            j += step;
+            while circular(&children, j) != entry {
+                let bv = circular(&children, j);
+                j += step;
+                if self.labels[bv] == 1 {
+                    continue;
+                }
+                if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.labels[v] != 0) {
+                    self.labels[v] = 0;
+                    let mate = self.endpoint(self.mates[self.bases[bv]]);
+                    self.labels[mate] = 0;
+                    self.assign_label(v, 2, self.label_ends[v]);
+                }
+            }
+        }
+        self.labels[b] = 0;
+        self.label_ends[b] = usize::MAX;
+        self.children[b].clear();
+        self.child_ends[b].clear();
+        self.bases[b] = usize::MAX;
+        self.best_edges[b] = usize::MAX;
+        self.blossom_best_edges[b] = None;
+        self.unused_blossoms.push(b);
+    }
+
+    // Swap matched and unmatched edges along the path going from vertex `v` to
+    // the base of blossom `b`, which becomes `v`.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.parents[t] != b {
+            t = self.parents[t];
+        }
+        if t >= self.size {
+            self.augment_blossom(t, v);
+        }
+        let children = self.children[b].clone();
+        let ends = self.child_ends[b].clone();
+        let i = children.iter().position(|&c| c == t).unwrap();
+        let mut j = i as isize;
+        let (step, trick) = if i & 1 == 1 {
+            j -= children.len() as isize;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        while j != 0 {
+            j += step;
+            let t = circular(&children, j);
+            let p = circular(&ends, j - trick as isize) ^ trick;
+            if t >= self.size {
+                self.augment_blossom(t, self.endpoint(p));
+            }
+            j += step;
+            let t = circular(&children, j);
+            if t >= self.size {
+                self.augment_blossom(t, self.endpoint(p ^ 1));
+            }
+            let (q, r) = (self.endpoint(p), self.endpoint(p ^ 1));
+            self.mates[q] = p ^ 1;
+            self.mates[r] = p;
+        }
+        self.children[b].rotate_left(i);
+        self.child_ends[b].rotate_left(i);
+        self.bases[b] = self.bases[self.children[b][0]];
+    }
+
+    // Augment the matching along the path going through edge `k`, which links two
+    // S-vertices in different alternating trees.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.size {
+                    self.augment_blossom(bs, s);
+                }
+                self.mates[s] = p;
+                if self.label_ends[bs] == usize::MAX {
+                    break;
+                }
+                let bt = self.in_blossom[self.endpoint(self.label_ends[bs])];
+                s = self.endpoint(self.label_ends[bt]);
+                let j = self.endpoint(self.label_ends[bt] ^ 1);
+                if bt >= self.size {
+                    self.augment_blossom(bt, j);
+                }
+                self.mates[j] = self.label_ends[bt];
+                p = self.label_ends[bt] ^ 1;
+            }
+        }
+    }
+
+    // Scan the S-vertices to be seen, and return `true` if the matching has been
+    // augmented.
+    fn scan(&mut self) -> bool {
+        while let Some(v) = self.to_see.pop() {
+            for i in 0..self.neighbour_ends[v].len() {
+                let p = self.neighbour_ends[v][i];
+                let k = p / 2;
+                let w = self.endpoint(p);
+                if self.in_blossom[v] == self.in_blossom[w] {
+                    continue;
+                }
+                let mut slack = C::zero();
+                if !self.allowed[k] {
+                    slack = self.slack(k);
+                    if slack <= C::zero() {
+                        self.allowed[k] = true;
+                    }
+                }
+                let bw = self.in_blossom[w];
+                if self.allowed[k] {
+                    if self.labels[bw] == 0 {
+                        self.assign_label(w, 2, p ^ 1);
+                    } else if self.labels[bw] == 1 {
+                        if let Some(base) = self.scan_blossom(v, w) {
+                            self.add_blossom(base, k);
+                        } else {
+                            self.augment_matching(k);
+                            return true;
+                        }
+                    } else if self.labels[w] == 0 {
+                        self.labels[w] = 2;
+                        self.label_ends[w] = p ^ 1;
+                    }
+                } else if self.labels[bw] == 1 {
+                    let b = self.in_blossom[v];
+                    if self.best_edges[b] == usize::MAX || slack < self.slack(self.best_edges[b]) {
+                        self.best_edges[b] = k;
+                    }
+                } else if self.labels[w] == 0
+                    && (self.best_edges[w] == usize::MAX || slack < self.slack(self.best_edges[w]))
+                {
+                    self.best_edges[w] = k;
+                }
+            }
+        }
+        false
+    }
+
+    // Update the dual variables so that a new edge can be used or a blossom be
+    // expanded, and return `false` if the optimum has been reached instead.
+    fn update_duals(&mut self) -> bool {
+        let n = self.size;
+        let two = C::one() + C::one();
+        // No edge, no blossom to expand: `None`. Vertex dual variables reaching zero:
+        // `Some(1, ...)`. Edge to an unlabelled vertex: `Some(2, ...)`. Edge between
+        // two S-blossoms: `Some(3, ...)`. T-blossom to expand: `Some(4, ...)`.
+        let mut best: Option<(u8, C, usize)> = None;
+        if !self.max_cardinality {
+            best = Some((1, *self.duals[..n].iter().min().unwrap(), usize::MAX));
+        }
+        for v in 0..n {
+            if self.labels[self.in_blossom[v]] == 0 && self.best_edges[v] != usize::MAX {
+                let delta = self.slack(self.best_edges[v]);
+                if best.map_or(true, |(_, d, _)| delta < d) {
+                    best = Some((2, delta, self.best_edges[v]));
+                }
+            }
+        }
+        for b in 0..2 * n {
+            if self.parents[b] == usize::MAX
+                && self.labels[b] == 1
+                && self.best_edges[b] != usize::MAX
+            {
+                let delta = self.slack(self.best_edges[b]) / two;
+                if best.map_or(true, |(_, d, _)| delta < d) {
+                    best = Some((3, delta, self.best_edges[b]));
+                }
+            }
+        }
+        for b in n..2 * n {
+            if self.bases[b] != usize::MAX
+                && self.parents[b] == usize::MAX
+                && self.labels[b] == 2
+                && best.map_or(true, |(_, d, _)| self.duals[b] < d)
+            {
+                best = Some((4, self.duals[b], b));
+            }
+        }
+        let (kind, delta, target) = best.unwrap_or_else(|| {
+            (
+                1,
+                (*self.duals[..n].iter().min().unwrap()).max(C::zero()),
+                usize::MAX,
+            )
+        });
+        for v in 0..n {
+            match self.labels[self.in_blossom[v]] {
+                1 => self.duals[v] = self.duals[v] - delta,
+                2 => self.duals[v] = self.duals[v] + delta,
+                _ => (),
+            }
+        }
+        for b in n..2 * n {
+            if self.bases[b] != usize::MAX && self.parents[b] == usize::MAX {
+                match self.labels[b] {
+                    1 => self.duals[b] = self.duals[b] + delta,
+                    2 => self.duals[b] = self.duals[b] - delta,
+                    _ => (),
+                }
+            }
+        }
+        match kind {
+            1 => return false,
+            2 => {
+                self.allowed[target] = true;
+                let (i, j, _) = self.edges[target];
+                self.to_see.push(if self.labels[self.in_blossom[i]] == 0 {
+                    j
+                } else {
+                    i
+                });
+            }
+            3 => {
+                self.allowed[target] = true;
+                self.to_see.push(self.edges[target].0);
+            }
+            _ => self.expand_blossom(target, false),
+        }
+        true
+    }
+
+    // Return the mate of every vertex, if any, with the weight of the edge linking them.
+    fn solve(mut self) -> Vec<Option<(usize, C)>> {
+        let n = self.size;
+        // Every stage augments the matching, or proves that it is optimal.
+        for _ in 0..n {
+            self.labels.fill(0);
+            self.best_edges.fill(usize::MAX);
+            for best in &mut self.blossom_best_edges[n..] {
+                *best = None;
+            }
+            self.allowed.fill(false);
+            self.to_see.clear();
+            for v in 0..n {
+                if self.mates[v] == usize::MAX && self.labels[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, 1, usize::MAX);
+                }
+            }
+            let augmented = loop {
+                if self.scan() {
+                    break true;
+                }
+                if !self.update_duals() {
+                    break false;
+                }
+            };
+            if !augmented {
+                break;
+            }
+            // Expand the S-blossoms whose dual variable is zero.
+            for b in n..2 * n {
+                if self.parents[b] == usize::MAX
+                    && self.bases[b] != usize::MAX
+                    && self.labels[b] == 1
+                    && self.duals[b] == C::zero()
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+        (0..n)
+            .map(|v| {
+                let p = self.mates[v];
+                (p != usize::MAX).then(|| (self.endpoint(p), self.edges[p / 2].2))
+            })
+            .collect()
+    }
+}
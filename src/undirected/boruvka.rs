@@ -0,0 +1,202 @@
+//! Find minimum-spanning-forest in an undirected graph using
+//! [Borůvka's algorithm](https://en.wikipedia.org/wiki/Borůvka's_algorithm).
+
+use indexmap::IndexSet;
+use std::hash::Hash;
+
+use crate::disjoint_set::UnionFind;
+
+// Edges are compared by weight, then by index so that all the weights
+// can be considered as distinct.
+fn lighter<C: Ord>(edges: &[(usize, usize, C)], a: usize, b: usize) -> bool {
+    (&edges[a].2, a) < (&edges[b].2, b)
+}
+
+// Add the cheapest edge of every component to the forest, and update the
+// component of every node. Return `false` if no edge has been added.
+fn merge<C: Clone>(
+    edges: &[(usize, usize, C)],
+    cheapest: impl IntoIterator<Item = usize>,
+    components: &mut [usize],
+    forest: &mut Vec<(usize, usize, C)>,
+) -> bool {
+    let mut sets = UnionFind::new(components.len());
+    let previous_len = forest.len();
+    for edge in cheapest {
+        let (a, b, ref w) = edges[edge];
+        if sets.union(components[a], components[b]) {
+            forest.push((a, b, w.clone()));
+        }
+    }
+    for component in components.iter_mut() {
+        *component = sets.find(*component);
+    }
+    forest.len() > previous_len
+}
+
+/// Minimal-spanning-forest for nodes with integer indices. The nodes must have
+/// consecutives indices between 0 and `number_of_nodes`-1.
+///
+/// # Panics
+///
+/// This function panics if a node is outside the range [0, `number_of_nodes`-1].
+pub fn boruvka_indices<C>(
+    number_of_nodes: usize,
+    edges: &[(usize, usize, C)],
+) -> Vec<(usize, usize, C)>
+where
+    C: Clone + Ord,
+{
+    let mut components = (0..number_of_nodes).collect::<Vec<_>>();
+    let mut live = (0..edges.len()).collect::<Vec<_>>();
+    let mut cheapest = vec![usize::MAX; number_of_nodes];
+    let mut forest = Vec::new();
+    loop {
+        live.retain(|&e| components[edges[e].0] != components[edges[e].1]);
+        for &e in &live {
+            for c in [components[edges[e].0], components[edges[e].1]] {
+                if cheapest[c] == usize::MAX || lighter(edges, e, cheapest[c]) {
+                    cheapest[c] = e;
+                }
+            }
+        }
+        let selected = cheapest.iter().copied().filter(|&e| e != usize::MAX);
+        if !merge(edges, selected, &mut components, &mut forest) {
+            return forest;
+        }
+        cheapest.fill(usize::MAX);
+    }
+}
+
+/// Find a minimum-spanning-forest using Borůvka's algorithm. From a collection
+/// of weighted edges, return a vector of edges forming a minimum-spanning-forest.
+///
+/// The edges are returned grouped by the phase of the algorithm in which they have
+/// been selected, not sorted by weight as in [`kruskal`](super::kruskal::kruskal). A
+/// parallel version, `par_boruvka`, is available if the `rayon` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::boruvka;
+///
+/// // Example from https://en.wikipedia.org/wiki/Kruskal's_algorithm
+/// let edges = [('a', 'b', 3), ('a', 'e', 1), ('b', 'c', 5), ('b', 'e', 4),
+///              ('c', 'd', 2), ('c', 'e', 6), ('d', 'e', 7)];
+/// let forest = boruvka(&edges);
+/// assert_eq!(forest.len(), 4);
+/// assert_eq!(forest.iter().map(|&(_, _, w)| w).sum::<i32>(), 11);
+/// ```
+pub fn boruvka<N, C>(edges: &[(N, N, C)]) -> Vec<(&N, &N, C)>
+where
+    N: Hash + Eq,
+    C: Clone + Ord,
+{
+    let (nodes, edges) = index_edges(edges);
+    boruvka_indices(nodes.len(), &edges)
+        .into_iter()
+        .map(|(ia, ib, w)| (nodes[ia], nodes[ib], w))
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+fn index_edges<N, C>(edges: &[(N, N, C)]) -> (IndexSet<&N>, Vec<(usize, usize, C)>)
+where
+    N: Hash + Eq,
+    C: Clone,
+{
+    let mut nodes = IndexSet::new();
+    let edges = edges
+        .iter()
+        .map(|(a, b, w)| {
+            let ia = nodes.insert_full(a).0;
+            let ib = nodes.insert_full(b).0;
+            (ia, ib, w.clone())
+        })
+        .collect();
+    (nodes, edges)
+}
+
+#[cfg(feature = "rayon")]
+pub use self::parallel::*;
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use rayon::prelude::*;
+    use std::hash::Hash;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::{index_edges, lighter, merge};
+
+    /// Parallel minimal-spanning-forest for nodes with integer indices. The nodes must have
+    /// consecutives indices between 0 and `number_of_nodes`-1.
+    ///
+    /// This function is only available if the `rayon` feature is enabled.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if a node is outside the range [0, `number_of_nodes`-1].
+    pub fn par_boruvka_indices<C>(
+        number_of_nodes: usize,
+        edges: &[(usize, usize, C)],
+    ) -> Vec<(usize, usize, C)>
+    where
+        C: Clone + Ord + Sync,
+    {
+        let mut components = (0..number_of_nodes).collect::<Vec<_>>();
+        let mut live = (0..edges.len()).collect::<Vec<_>>();
+        let cheapest = (0..number_of_nodes)
+            .map(|_| AtomicUsize::new(usize::MAX))
+            .collect::<Vec<_>>();
+        let mut forest = Vec::new();
+        loop {
+            live = live
+                .into_par_iter()
+                .filter(|&e| components[edges[e].0] != components[edges[e].1])
+                .collect();
+            live.par_iter().for_each(|&e| {
+                for c in [components[edges[e].0], components[edges[e].1]] {
+                    let mut current = cheapest[c].load(Ordering::Relaxed);
+                    while current == usize::MAX || lighter(edges, e, current) {
+                        match cheapest[c].compare_exchange_weak(
+                            current,
+                            e,
+                            Ordering::Relaxed,
+                            Ordering::Relaxed,
+                        ) {
+                            Ok(_) => break,
+                            Err(previous) => current = previous,
+                        }
+                    }
+                }
+            });
+            let selected = cheapest
+                .iter()
+                .map(|c| c.swap(usize::MAX, Ordering::Relaxed))
+                .filter(|&e| e != usize::MAX)
+                .collect::<Vec<_>>();
+            if !merge(edges, selected, &mut components, &mut forest) {
+                return forest;
+            }
+        }
+    }
+
+    /// Find a minimum-spanning-forest using Borůvka's algorithm, looking for the
+    /// cheapest edge of every component in parallel. From a collection of weighted
+    /// edges, return a vector of edges forming a minimum-spanning-forest.
+    ///
+    /// This function is only available if the `rayon` feature is enabled. It returns
+    /// the same edges as [`boruvka`](super::boruvka) in the same order, and is worth
+    /// using on very large collections of edges.
+    pub fn par_boruvka<N, C>(edges: &[(N, N, C)]) -> Vec<(&N, &N, C)>
+    where
+        N: Hash + Eq,
+        C: Clone + Ord + Sync,
+    {
+        let (nodes, edges) = index_edges(edges);
+        par_boruvka_indices(nodes.len(), &edges)
+            .into_iter()
+            .map(|(ia, ib, w)| (nodes[ia], nodes[ib], w))
+            .collect()
+    }
+}
@@ -0,0 +1,188 @@
+//! Find the cliques of an undirected graph using the [Bron-Kerbosch
+//! algorithm](https://en.wikipedia.org/wiki/Bron–Kerbosch_algorithm).
+
+use fixedbitset::FixedBitSet;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+
+use super::index_undirected_graph;
+use crate::directed::FxIndexSet;
+
+/// Enumerate the maximal cliques of an undirected graph, that is the sets of
+/// vertices which are all neighbours of each other and which cannot be extended
+/// by adding another vertex.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric,
+///   and self-loops are ignored.
+///
+/// Cliques are generated lazily using the Bron-Kerbosch algorithm with pivoting,
+/// and an isolated vertex forms a clique on its own. The adjacency of the graph is
+/// stored as a matrix of bits, which uses O(|V|²) memory.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::maximal_cliques;
+/// use std::collections::HashSet;
+///
+/// // Triangle 1-2-3, and edge 3-4.
+/// let neighbours = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![3],
+///     _ => vec![4],
+/// };
+/// let cliques = maximal_cliques(&[1], neighbours).collect::<Vec<_>>();
+/// assert_eq!(cliques.len(), 2);
+/// assert!(cliques.contains(&HashSet::from([1, 2, 3])));
+/// assert!(cliques.contains(&HashSet::from([3, 4])));
+/// ```
+pub fn maximal_cliques<N, FN, IN>(starts: &[N], neighbours: FN) -> MaximalCliques<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = index_undirected_graph(starts, neighbours);
+    let size = nodes.len();
+    let adjacency = graph
+        .into_iter()
+        .enumerate()
+        .map(|(n, neighbours)| {
+            let mut set = FixedBitSet::with_capacity(size);
+            set.extend(neighbours.into_iter().filter(|&m| m != n));
+            set
+        })
+        .collect::<Vec<_>>();
+    let mut candidates = FixedBitSet::with_capacity(size);
+    candidates.insert_range(..);
+    let mut cliques = MaximalCliques {
+        nodes,
+        adjacency,
+        clique: Vec::new(),
+        stack: Vec::new(),
+        min_size: 0,
+    };
+    cliques.push(candidates, FixedBitSet::with_capacity(size));
+    cliques
+}
+
+/// Find a clique with the largest number of vertices in an undirected graph.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric,
+///   and self-loops are ignored.
+///
+/// The search enumerates [maximal cliques](maximal_cliques), skipping the ones
+/// which cannot be larger than the best clique found so far. Finding a maximum clique
+/// is NP-hard, and this function should only be used on reasonably small graphs.
+/// An empty set is returned if the graph has no vertices.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::maximum_clique;
+/// use std::collections::HashSet;
+///
+/// // Vertices 1 to 10, with an edge between a vertex and its multiples.
+/// let neighbours = |&n: &u32| (1..=10).filter(move |&m| m != n && (m % n == 0 || n % m == 0));
+/// assert_eq!(maximum_clique(&[1], neighbours), HashSet::from([1, 2, 4, 8]));
+/// ```
+pub fn maximum_clique<N, FN, IN>(starts: &[N], neighbours: FN) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut cliques = maximal_cliques(starts, neighbours);
+    let mut best = HashSet::new();
+    while let Some(clique) = cliques.next() {
+        cliques.min_size = clique.len() + 1;
+        best = clique;
+    }
+    best
+}
+
+/// Struct returned by [`maximal_cliques`](crate::undirected::cliques::maximal_cliques).
+pub struct MaximalCliques<N> {
+    nodes: FxIndexSet<N>,
+    adjacency: Vec<FixedBitSet>,
+    // Vertices of the clique being built, one for every frame except the first one.
+    clique: Vec<usize>,
+    stack: Vec<Frame>,
+    // Cliques smaller than this are not generated.
+    min_size: usize,
+}
+
+struct Frame {
+    // Vertices which can extend the current clique, and vertices which could extend
+    // it but have already been explored.
+    candidates: FixedBitSet,
+    excluded: FixedBitSet,
+    // Candidates which are not neighbours of the pivot.
+    to_explore: Vec<usize>,
+}
+
+impl<N> MaximalCliques<N> {
+    fn push(&mut self, candidates: FixedBitSet, excluded: FixedBitSet) {
+        let pivot = candidates
+            .ones()
+            .chain(excluded.ones())
+            .max_by_key(|&u| self.adjacency[u].intersection(&candidates).count());
+        let to_explore = match pivot {
+            Some(pivot) => candidates.difference(&self.adjacency[pivot]).collect(),
+            None => Vec::new(),
+        };
+        self.stack.push(Frame {
+            candidates,
+            excluded,
+            to_explore,
+        });
+    }
+}
+
+impl<N> Iterator for MaximalCliques<N>
+where
+    N: Eq + Hash + Clone,
+{
+    type Item = HashSet<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            if let Some(v) = frame.to_explore.pop() {
+                let mut candidates = frame.candidates.clone();
+                candidates.intersect_with(&self.adjacency[v]);
+                let mut excluded = frame.excluded.clone();
+                excluded.intersect_with(&self.adjacency[v]);
+                frame.candidates.set(v, false);
+                frame.excluded.insert(v);
+                let size = self.clique.len() + 1;
+                if candidates.count_ones(..) == 0 {
+                    if excluded.count_ones(..) == 0 && size >= self.min_size {
+                        return Some(
+                            self.clique
+                                .iter()
+                                .chain(Some(&v))
+                                .map(|&n| self.nodes[n].clone())
+                                .collect(),
+                        );
+                    }
+                } else if size + candidates.count_ones(..) >= self.min_size {
+                    self.clique.push(v);
+                    self.push(candidates, excluded);
+                }
+            } else {
+                self.stack.pop();
+                self.clique.pop();
+            }
+        }
+    }
+}
+
+impl<N> FusedIterator for MaximalCliques<N> where N: Eq + Hash + Clone {}
@@ -0,0 +1,296 @@
+//! Colour the vertices of an undirected graph so that no two neighbours
+//! share the same colour.
+
+use fixedbitset::FixedBitSet;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use super::index_undirected_graph;
+use crate::directed::FxIndexSet;
+
+/// Order in which vertices are considered by [`greedy_colouring`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColouringOrder {
+    /// Vertices are coloured in the order they are discovered, starting
+    /// with the ones given as start points.
+    Natural,
+    /// Vertices with the largest number of neighbours are coloured first
+    /// (Welsh-Powell algorithm).
+    LargestFirst,
+    /// The vertex with the smallest number of neighbours is repeatedly removed
+    /// from the graph, and vertices are coloured in the reverse order of their
+    /// removal. This guarantees that planar graphs use at most 6 colours.
+    SmallestLast,
+}
+
+// Build the adjacency lists of the graph, without self-loops.
+fn simple_graph<N, FN, IN>(starts: &[N], neighbours: FN) -> (FxIndexSet<N>, Vec<Vec<usize>>)
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, mut graph) = index_undirected_graph(starts, neighbours);
+    for (n, neighbours) in graph.iter_mut().enumerate() {
+        neighbours.retain(|&m| m != n);
+    }
+    (nodes, graph)
+}
+
+fn to_map<N>(nodes: FxIndexSet<N>, colours: &[usize]) -> HashMap<N, usize>
+where
+    N: Eq + Hash,
+{
+    nodes.into_iter().zip(colours.iter().copied()).collect()
+}
+
+// Return the smallest colour not used by the neighbours of `node`.
+fn smallest_free_colour(graph: &[Vec<usize>], colours: &[usize], node: usize) -> usize {
+    let mut used = FixedBitSet::with_capacity(graph[node].len() + 1);
+    for &c in graph[node].iter().map(|&n| &colours[n]) {
+        if c < used.len() {
+            used.insert(c);
+        }
+    }
+    (0..).find(|&c| !used.contains(c)).unwrap()
+}
+
+fn smallest_last_order(graph: &[Vec<usize>]) -> Vec<usize> {
+    let mut degrees = graph.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut removed = FixedBitSet::with_capacity(graph.len());
+    let mut to_see = degrees
+        .iter()
+        .enumerate()
+        .map(|(n, &d)| Reverse((d, n)))
+        .collect::<BinaryHeap<_>>();
+    let mut order = Vec::with_capacity(graph.len());
+    while let Some(Reverse((degree, n))) = to_see.pop() {
+        if removed[n] || degree != degrees[n] {
+            continue;
+        }
+        removed.insert(n);
+        order.push(n);
+        for &m in &graph[n] {
+            if !removed[m] {
+                degrees[m] -= 1;
+                to_see.push(Reverse((degrees[m], m)));
+            }
+        }
+    }
+    order.reverse();
+    order
+}
+
+/// Colour the vertices of an undirected graph with a greedy algorithm: vertices
+/// are considered one by one, and each of them receives the smallest colour not
+/// already used by one of its neighbours.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric,
+///   and self-loops are ignored.
+/// - `order` is the order in which the vertices are considered.
+///
+/// The function returns the colour of every vertex, colours being numbered
+/// from 0. The number of colours used is not necessarily minimal, see
+/// [`exact_colouring`] if this is required.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // A cycle with 5 vertices needs 3 colours.
+/// let colours = greedy_colouring(&[0], |&n: &u32| vec![(n + 1) % 5], ColouringOrder::Natural);
+/// assert_eq!(colours.len(), 5);
+/// assert_eq!(colours.values().max(), Some(&2));
+/// assert!((0..5).all(|n| colours[&n] != colours[&((n + 1) % 5)]));
+/// ```
+pub fn greedy_colouring<N, FN, IN>(
+    starts: &[N],
+    neighbours: FN,
+    order: ColouringOrder,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = simple_graph(starts, neighbours);
+    let order = match order {
+        ColouringOrder::Natural => (0..graph.len()).collect(),
+        ColouringOrder::LargestFirst => {
+            let mut order = (0..graph.len()).collect::<Vec<_>>();
+            order.sort_by_key(|&n| Reverse(graph[n].len()));
+            order
+        }
+        ColouringOrder::SmallestLast => smallest_last_order(&graph),
+    };
+    let mut colours = vec![usize::MAX; graph.len()];
+    for n in order {
+        colours[n] = smallest_free_colour(&graph, &colours, n);
+    }
+    to_map(nodes, &colours)
+}
+
+fn dsatur(graph: &[Vec<usize>]) -> Vec<usize> {
+    let mut colours = vec![usize::MAX; graph.len()];
+    // Colours used by the neighbours of every vertex, and number of them.
+    let mut neighbour_colours = vec![FixedBitSet::with_capacity(0); graph.len()];
+    let mut saturations = vec![0; graph.len()];
+    let mut to_see = (0..graph.len())
+        .map(|n| (0, graph[n].len(), Reverse(n)))
+        .collect::<BinaryHeap<_>>();
+    while let Some((saturation, _, Reverse(n))) = to_see.pop() {
+        if colours[n] != usize::MAX || saturation != saturations[n] {
+            continue;
+        }
+        let colour = smallest_free_colour(graph, &colours, n);
+        colours[n] = colour;
+        for &m in &graph[n] {
+            let used = &mut neighbour_colours[m];
+            if colours[m] == usize::MAX && !used.contains(colour) {
+                used.grow(colour + 1);
+                used.insert(colour);
+                saturations[m] += 1;
+                to_see.push((saturations[m], graph[m].len(), Reverse(m)));
+            }
+        }
+    }
+    colours
+}
+
+/// Colour the vertices of an undirected graph using the [DSATUR
+/// algorithm](https://en.wikipedia.org/wiki/DSatur): the next vertex to colour is
+/// always the one whose neighbours already use the largest number of distinct colours,
+/// and it receives the smallest colour not used by one of them.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric,
+///   and self-loops are ignored.
+///
+/// The function returns the colour of every vertex, colours being numbered
+/// from 0. DSATUR is exact for bipartite graphs, and usually uses fewer colours
+/// than [`greedy_colouring`], but the number of colours is not guaranteed to
+/// be minimal.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dsatur_colouring;
+///
+/// // A wheel with a center 0 and 6 vertices around it needs 3 colours.
+/// let neighbours = |&n: &u32| match n {
+///     0 => (1..=6).collect(),
+///     _ => vec![0, n % 6 + 1],
+/// };
+/// let colours = dsatur_colouring(&[0], neighbours);
+/// assert_eq!(colours.values().max(), Some(&2));
+/// ```
+pub fn dsatur_colouring<N, FN, IN>(starts: &[N], neighbours: FN) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = simple_graph(starts, neighbours);
+    let colours = dsatur(&graph);
+    to_map(nodes, &colours)
+}
+
+// Try to colour the remaining vertices using colours smaller than `limit`. The
+// uncoloured vertex with the largest number of distinct colours amongst its
+// neighbours is considered first. A new colour is only introduced as the
+// smallest unused one, to avoid exploring permutations of the colours.
+fn extend_colouring(
+    graph: &[Vec<usize>],
+    colours: &mut [usize],
+    remaining: usize,
+    used: usize,
+    limit: usize,
+) -> bool {
+    if remaining == 0 {
+        return true;
+    }
+    let mut forbidden = FixedBitSet::with_capacity(limit + 1);
+    let mut best: Option<(usize, usize, usize, FixedBitSet)> = None;
+    for n in (0..graph.len()).filter(|&n| colours[n] == usize::MAX) {
+        forbidden.clear();
+        for &c in graph[n].iter().map(|&m| &colours[m]) {
+            if c < limit {
+                forbidden.insert(c);
+            }
+        }
+        let saturation = forbidden.count_ones(..);
+        let key = (saturation, graph[n].len());
+        if best.as_ref().map_or(true, |b| key > (b.0, b.1)) {
+            best = Some((saturation, graph[n].len(), n, forbidden.clone()));
+        }
+    }
+    let (_, _, n, forbidden) = best.unwrap();
+    for colour in (0..limit.min(used + 1)).filter(|&c| !forbidden[c]) {
+        colours[n] = colour;
+        if extend_colouring(graph, colours, remaining - 1, used.max(colour + 1), limit) {
+            return true;
+        }
+    }
+    colours[n] = usize::MAX;
+    false
+}
+
+/// Colour the vertices of an undirected graph using the smallest possible number of
+/// colours, which is the chromatic number of the graph.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node.
+///   Vertices that were not present in `starts` may be returned as well.
+///   Edges are considered as undirected even if `neighbours` is not symmetric,
+///   and self-loops are ignored.
+///
+/// The function returns the colour of every vertex, colours being numbered
+/// from 0: the chromatic number is one more than the largest colour, or 0
+/// for an empty graph.
+///
+/// A colouring is first computed using [`dsatur_colouring`], and backtracking
+/// is then used to look for colourings with fewer colours. Finding the chromatic
+/// number of a graph is NP-hard, and the backtracking takes exponential time in
+/// the number of vertices in the worst case: while some sparse or structured graphs
+/// with hundreds of vertices are coloured quickly, some dense random graphs with a
+/// few dozen vertices may already take a very long time.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // Vertices 1 to 8, with an edge between vertices whose sum is a prime number.
+/// let neighbours = |&n: &u32| {
+///     (1..=8).filter(move |&m| [3, 5, 7, 11, 13].contains(&(n + m)))
+/// };
+/// let colours = exact_colouring(&[1], neighbours);
+/// assert_eq!(colours.len(), 8);
+/// assert_eq!(colours.values().max(), Some(&1));
+/// ```
+pub fn exact_colouring<N, FN, IN>(starts: &[N], neighbours: FN) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, graph) = simple_graph(starts, neighbours);
+    let mut best = dsatur(&graph);
+    let mut count = best.iter().map(|&c| c + 1).max().unwrap_or(0);
+    while count > 1 {
+        let mut colours = vec![usize::MAX; graph.len()];
+        if !extend_colouring(&graph, &mut colours, graph.len(), 0, count - 1) {
+            break;
+        }
+        count = colours.iter().map(|&c| c + 1).max().unwrap_or(0);
+        best = colours;
+    }
+    to_map(nodes, &best)
+}
@@ -7,16 +7,7 @@ use std::hash::Hash;
 use std::iter::once;
 use std::usize;
 
-/// Lookup entries until we get the same value as the index, with
-/// path halving. Adding a new entry to the table consists
-/// into pushing the table length.
-fn get_and_redirect(table: &mut [usize], mut idx: usize) -> usize {
-    while idx != table[idx] {
-        table[idx] = table[table[idx]];
-        idx = table[idx];
-    }
-    idx
-}
+use crate::disjoint_set::UnionFind;
 
 /// Separate components of an undirected graph into disjoint sets.
 ///
@@ -42,17 +33,13 @@ pub fn separate_components<N>(groups: &[Vec<N>]) -> (HashMap<&N, usize>, Vec<usi
 where
     N: Hash + Eq,
 {
-    let mut table = (0..groups.len()).collect_vec();
+    let mut sets = UnionFind::new(groups.len());
     let mut indices = HashMap::new();
-    for (mut group_index, group) in groups.iter().enumerate() {
-        if group.is_empty() {
-            table[group_index] = usize::max_value();
-        }
+    for (group_index, group) in groups.iter().enumerate() {
         for element in group {
             match indices.entry(element) {
                 Occupied(e) => {
-                    table[group_index] = get_and_redirect(&mut table, *e.get());
-                    group_index = table[group_index];
+                    sets.union(*e.get(), group_index);
                 }
                 Vacant(e) => {
                     e.insert(group_index);
@@ -61,16 +48,19 @@ where
         }
     }
     for group_index in indices.values_mut() {
-        *group_index = get_and_redirect(&mut table, *group_index);
-    }
-    for group_index in 0..groups.len() {
-        if table[group_index] != usize::max_value() {
-            let target = get_and_redirect(&mut table, group_index);
-            // Due to path halving, this particular entry might not
-            // be up-to-date yet.
-            table[group_index] = target;
-        }
+        *group_index = sets.find(*group_index);
     }
+    let table = groups
+        .iter()
+        .enumerate()
+        .map(|(group_index, group)| {
+            if group.is_empty() {
+                usize::max_value()
+            } else {
+                sets.find(group_index)
+            }
+        })
+        .collect();
     (indices, table)
 }
 
@@ -0,0 +1,158 @@
+//! Answer minimum cut queries between any two nodes of an undirected weighted graph
+//! using a [Gomory–Hu tree](https://en.wikipedia.org/wiki/Gomory–Hu_tree), built
+//! with Gusfield's algorithm and any of the maximum flow algorithms implementing
+//! [`EdmondsKarp`].
+
+use num_traits::{Bounded, Signed, Zero};
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+use crate::directed::edmonds_karp::EdmondsKarp;
+use crate::directed::FxIndexSet;
+
+/// Gomory–Hu tree of an undirected weighted graph: the weight of the minimum cut
+/// between two nodes of the graph is the smallest weight found on the path linking
+/// them in the tree, and removing this edge from the tree splits the nodes along
+/// such a cut.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// let edges = [
+///     ('a', 'b', 3), ('b', 'c', 2), ('c', 'a', 4),
+///     ('d', 'e', 3), ('e', 'f', 5), ('f', 'd', 2),
+///     ('a', 'd', 1), ('c', 'f', 2),
+/// ];
+/// let tree = GomoryHuTree::new::<SparseCapacity<_>>(&edges);
+/// assert_eq!(tree.min_cut(&'a', &'c'), Some(7));
+/// assert_eq!(tree.min_cut(&'b', &'e'), Some(3));
+/// assert_eq!(tree.min_cut(&'e', &'f'), Some(8));
+/// assert_eq!(tree.min_cut(&'a', &'z'), None);
+/// assert_eq!(tree.edges().len(), 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GomoryHuTree<N, C> {
+    nodes: FxIndexSet<N>,
+    parents: Vec<usize>,
+    weights: Vec<C>,
+    depths: Vec<usize>,
+}
+
+impl<N, C> GomoryHuTree<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Bounded + Signed + Ord + Copy,
+{
+    /// Build the Gomory–Hu tree of a graph given as a collection of weighted
+    /// edges, using `n-1` maximum flow computations with the `EK` algorithm, for
+    /// example [`SparseCapacity`](crate::directed::edmonds_karp::SparseCapacity)
+    /// or [`Dinic<SparseCapacity>`](crate::directed::dinic::Dinic), where `n` is
+    /// the number of nodes.
+    ///
+    /// Weights must be non-negative, several edges may link the same nodes, and
+    /// loops are ignored.
+    #[must_use]
+    pub fn new<EK: EdmondsKarp<C>>(edges: &[(N, N, C)]) -> Self {
+        let mut nodes = FxIndexSet::default();
+        let mut capacities = BTreeMap::new();
+        for (a, b, w) in edges {
+            let ia = nodes.insert_full(a.clone()).0;
+            let ib = nodes.insert_full(b.clone()).0;
+            if ia != ib {
+                let capacity = capacities
+                    .entry((ia.min(ib), ia.max(ib)))
+                    .or_insert_with(C::zero);
+                *capacity = *capacity + *w;
+            }
+        }
+        let size = nodes.len();
+        let mut parents = vec![0; size];
+        let mut weights = vec![C::zero(); size];
+        for source in 1..size {
+            let sink = parents[source];
+            let mut network = EK::new(size, source, sink);
+            network.omit_detailed_flows();
+            for (&(a, b), &capacity) in &capacities {
+                network.set_capacity(a, b, capacity);
+                network.set_capacity(b, a, capacity);
+            }
+            let (_, value) = network.augment();
+            let mut on_source_side = vec![false; size];
+            for n in network.min_cut().0 {
+                on_source_side[n] = true;
+            }
+            // Reattach the nodes on the source side of the cut to the source, and
+            // swap the source and the sink if the parent of the sink is on this side.
+            weights[source] = value;
+            for n in 0..size {
+                if n != source && on_source_side[n] && parents[n] == sink {
+                    parents[n] = source;
+                }
+            }
+            if on_source_side[parents[sink]] {
+                parents[source] = parents[sink];
+                parents[sink] = source;
+                weights[source] = weights[sink];
+                weights[sink] = value;
+            }
+        }
+        let mut depths = vec![usize::MAX; size];
+        if size > 0 {
+            depths[0] = 0;
+        }
+        for n in 0..size {
+            let mut path = Vec::new();
+            let mut node = n;
+            while depths[node] == usize::MAX {
+                path.push(node);
+                node = parents[node];
+            }
+            for (i, &n) in path.iter().rev().enumerate() {
+                depths[n] = depths[node] + i + 1;
+            }
+        }
+        Self {
+            nodes,
+            parents,
+            weights,
+            depths,
+        }
+    }
+
+    /// Return the edges of the tree, with their weight.
+    #[must_use]
+    pub fn edges(&self) -> Vec<(&N, &N, C)> {
+        (1..self.nodes.len())
+            .map(|n| {
+                (
+                    &self.nodes[n],
+                    &self.nodes[self.parents[n]],
+                    self.weights[n],
+                )
+            })
+            .collect()
+    }
+
+    /// Return the weight of the minimum cut separating `a` from `b` in the original
+    /// graph, or `None` if `a` and `b` are the same node or if one of them does not
+    /// belong to the graph.
+    #[must_use]
+    pub fn min_cut(&self, a: &N, b: &N) -> Option<C> {
+        let mut a = self.nodes.get_index_of(a)?;
+        let mut b = self.nodes.get_index_of(b)?;
+        if a == b {
+            return None;
+        }
+        let mut weight = C::max_value();
+        while a != b {
+            if self.depths[a] < self.depths[b] {
+                std::mem::swap(&mut a, &mut b);
+            }
+            weight = weight.min(self.weights[a]);
+            a = self.parents[a];
+        }
+        Some(weight)
+    }
+}
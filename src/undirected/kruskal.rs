@@ -3,41 +3,8 @@
 
 use indexmap::IndexSet;
 use std::hash::Hash;
-use std::mem;
 
-// Find parent and compress path by path halving.
-fn find(parents: &mut [usize], mut node: usize) -> usize {
-    while parents[node] != node {
-        parents[node] = parents[parents[node]];
-        node = parents[node];
-    }
-    node
-}
-
-#[test]
-fn test_path_halving() {
-    let mut parents = vec![0, 0, 1, 2, 3, 4, 5, 6];
-    assert_eq!(find(&mut parents, 7), 0);
-    assert_eq!(parents, vec![0, 0, 1, 1, 3, 3, 5, 5]);
-    assert_eq!(find(&mut parents, 7), 0);
-    assert_eq!(parents, vec![0, 0, 1, 0, 3, 3, 5, 3]);
-    assert_eq!(find(&mut parents, 7), 0);
-    assert_eq!(parents, vec![0, 0, 1, 0, 3, 3, 5, 0]);
-    assert_eq!(find(&mut parents, 6), 0);
-    assert_eq!(parents, vec![0, 0, 1, 0, 3, 3, 3, 0]);
-    assert_eq!(find(&mut parents, 6), 0);
-    assert_eq!(parents, vec![0, 0, 1, 0, 3, 3, 0, 0]);
-}
-
-fn union(parents: &mut [usize], ranks: &mut [usize], mut a: usize, mut b: usize) {
-    if ranks[a] < ranks[b] {
-        mem::swap(&mut a, &mut b);
-    }
-    parents[b] = a;
-    if ranks[a] == ranks[b] {
-        ranks[a] += 1;
-    }
-}
+use crate::disjoint_set::UnionFind;
 
 /// Minimal-spanning-tree for nodes with integer indices. The nodes must have
 /// consecutives indices between 0 and `number_of_nodes`-1.
@@ -52,21 +19,12 @@ pub fn kruskal_indices<C>(
 where
     C: Clone + Ord,
 {
-    let mut parents = (0..number_of_nodes).collect::<Vec<_>>();
-    let mut ranks = Vec::with_capacity(number_of_nodes);
-    ranks.resize(number_of_nodes, 1);
+    let mut sets = UnionFind::new(number_of_nodes);
     let mut edges = edges.to_vec();
     edges.sort_unstable_by(|a, b| a.2.cmp(&b.2));
-    edges.into_iter().filter_map(move |(a, b, w)| {
-        let ra = find(&mut parents, a);
-        let rb = find(&mut parents, b);
-        if ra == rb {
-            None
-        } else {
-            union(&mut parents, &mut ranks, ra, rb);
-            Some((a, b, w))
-        }
-    })
+    edges
+        .into_iter()
+        .filter_map(move |(a, b, w)| sets.union(a, b).then_some((a, b, w)))
 }
 
 /// Find a minimum-spanning-tree. From a collection of
@@ -80,7 +38,7 @@ where
     let mut nodes = IndexSet::new();
     let edges = edges
         .iter()
-        .map(|&(ref a, ref b, ref w)| {
+        .map(|(a, b, w)| {
             let ia = nodes.insert_full(a).0;
             let ib = nodes.insert_full(b).0;
             (ia, ib, w.clone())
@@ -1,4 +1,44 @@
 //! Algorithms for undirected graphs.
 
+pub mod biconnected_components;
+pub mod bipartition;
+pub mod blossom;
+pub mod boruvka;
+pub mod cliques;
+pub mod colouring;
 pub mod connected_components;
+pub mod gomory_hu;
 pub mod kruskal;
+pub mod prim;
+pub mod steiner_tree;
+pub mod stoer_wagner;
+
+use std::hash::Hash;
+
+use crate::directed::{index_graph, FxIndexSet};
+
+/// Explore a finite undirected graph from `nodes` and return the discovered nodes
+/// along with the sorted and deduplicated neighbours of every node, expressed as
+/// indices into the set of nodes. Edges are made symmetric if `neighbours` is not.
+fn index_undirected_graph<N, FN, IN>(
+    nodes: &[N],
+    neighbours: FN,
+) -> (FxIndexSet<N>, Vec<Vec<usize>>)
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, successors) = index_graph(nodes, neighbours);
+    let mut graph = successors.clone();
+    for (n, succs) in successors.into_iter().enumerate() {
+        for s in succs {
+            graph[s].push(n);
+        }
+    }
+    for neighbours in &mut graph {
+        neighbours.sort_unstable();
+        neighbours.dedup();
+    }
+    (nodes, graph)
+}
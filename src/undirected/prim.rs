@@ -0,0 +1,80 @@
+//! Find minimum-spanning-tree in an undirected graph using
+//! [Prim's algorithm](https://en.wikipedia.org/wiki/Prim's_algorithm).
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use crate::directed::FxIndexSet;
+
+/// Find a minimum-spanning-tree of the connected component containing `start`,
+/// using Prim's algorithm.
+///
+/// - `start` is the vertex from which the tree is grown.
+/// - `neighbours` returns a list of neighbours for a given vertex, along with the
+///   weight of the edge leading to the neighbour. Edges are expected to be given in
+///   both directions with the same weight.
+///
+/// Contrary to [`kruskal`](super::kruskal::kruskal), the graph does not need to be
+/// given as an explicit list of edges, and only the vertices reachable from `start`
+/// are explored.
+///
+/// The function returns the edges of the tree in the order they have been added,
+/// each one as a `(parent, child, weight)` triple where `parent` was already part of
+/// the tree. The first edge starts from `start`, and the result is empty if `start`
+/// has no neighbours.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::prim;
+///
+/// // Example from https://en.wikipedia.org/wiki/Kruskal's_algorithm
+/// let edges = [('a', 'b', 3), ('a', 'e', 1), ('b', 'c', 5), ('b', 'e', 4),
+///              ('c', 'd', 2), ('c', 'e', 6), ('d', 'e', 7)];
+/// let neighbours = |&n: &char| {
+///     edges.iter().filter_map(move |&(a, b, w)| {
+///         if a == n { Some((b, w)) } else if b == n { Some((a, w)) } else { None }
+///     })
+/// };
+/// assert_eq!(
+///     prim(&'a', neighbours),
+///     vec![('a', 'e', 1), ('a', 'b', 3), ('b', 'c', 5), ('c', 'd', 2)]
+/// );
+/// ```
+pub fn prim<N, C, FN, IN>(start: &N, mut neighbours: FN) -> Vec<(N, N, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = FxIndexSet::default();
+    nodes.insert(start.clone());
+    let mut in_tree = vec![false];
+    let mut tree = Vec::new();
+    let mut to_see = BinaryHeap::new();
+    let mut node = 0;
+    loop {
+        in_tree[node] = true;
+        for (neighbour, weight) in neighbours(nodes.get_index(node).unwrap()) {
+            let (index, added) = nodes.insert_full(neighbour);
+            if added {
+                in_tree.push(false);
+            }
+            if !in_tree[index] {
+                to_see.push(Reverse((weight, index, node)));
+            }
+        }
+        node = loop {
+            match to_see.pop() {
+                Some(Reverse((weight, index, parent))) if !in_tree[index] => {
+                    tree.push((nodes[parent].clone(), nodes[index].clone(), weight));
+                    break index;
+                }
+                Some(_) => (),
+                None => return tree,
+            }
+        };
+    }
+}
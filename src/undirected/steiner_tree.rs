@@ -0,0 +1,129 @@
+//! Approximate a minimum [Steiner tree](https://en.wikipedia.org/wiki/Steiner_tree_problem)
+//! connecting a set of terminals in a weighted undirected graph.
+
+use indexmap::IndexMap;
+use num_traits::Zero;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use super::kruskal::kruskal_indices;
+use crate::directed::dijkstra::{build_path, dijkstra_partial};
+use crate::directed::FxIndexSet;
+
+/// Find a tree of small total weight connecting all the `terminals` of a weighted
+/// undirected graph, possibly through other vertices.
+///
+/// - `terminals` is the collection of vertices to connect.
+/// - `neighbours` returns a list of neighbours for a given vertex, along with the
+///   weight of the edge leading to the neighbour. Edges are expected to be given in
+///   both directions with the same weight, and weights must not be negative.
+///
+/// The function returns `None` if some terminals cannot be reached from the others,
+/// or the edges of the tree as `(from, to, weight)` triples otherwise.
+///
+/// Finding a minimum Steiner tree is NP-hard. This function uses the metric closure
+/// method: shortest paths between terminals are computed using
+/// [`dijkstra`](crate::directed::dijkstra), a minimum-spanning-tree of the terminals
+/// is built using [`kruskal`](super::kruskal) with those paths as edges, and the
+/// paths of this tree are expanded, made into a tree again and stripped of
+/// non-terminal leaves. The weight of the result is guaranteed to be less than twice
+/// the weight of a minimum Steiner tree.
+///
+/// The graph is explored from every terminal until all other terminals have been
+/// reached, so it may be infinite as long as all terminals are connected.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::steiner_tree;
+///
+/// // Three terminals at the corners of a star: going through the center is
+/// // cheaper than using the direct links between terminals.
+/// let edges = [('a', 'b', 5), ('b', 'c', 5), ('a', 'c', 5), ('a', 'z', 2), ('b', 'z', 2), ('c', 'z', 2)];
+/// let neighbours = |&n: &char| {
+///     edges.iter().filter_map(move |&(a, b, w)| {
+///         if a == n { Some((b, w)) } else if b == n { Some((a, w)) } else { None }
+///     })
+/// };
+/// let tree = steiner_tree(&['a', 'b', 'c'], neighbours).unwrap();
+/// assert_eq!(tree.iter().map(|&(_, _, w)| w).sum::<u32>(), 6);
+/// ```
+pub fn steiner_tree<N, C, FN, IN>(terminals: &[N], mut neighbours: FN) -> Option<Vec<(N, N, C)>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let terminals = terminals.iter().cloned().collect::<FxIndexSet<N>>();
+    // Shortest paths from every terminal to the following ones.
+    let mut closure = Vec::new();
+    let mut parents = Vec::with_capacity(terminals.len());
+    for (i, start) in terminals
+        .iter()
+        .enumerate()
+        .take(terminals.len().saturating_sub(1))
+    {
+        // A node may be examined several times by `dijkstra_partial`.
+        let mut seen = vec![false; terminals.len()];
+        let mut remaining = terminals.len() - i - 1;
+        let (reached, _) = dijkstra_partial(start, &mut neighbours, |n| {
+            if let Some(j) = terminals.get_index_of(n) {
+                if j > i && !seen[j] {
+                    seen[j] = true;
+                    remaining -= 1;
+                }
+            }
+            remaining == 0
+        });
+        for j in i + 1..terminals.len() {
+            closure.push((i, j, reached.get(&terminals[j])?.1));
+        }
+        parents.push(reached);
+    }
+    // Expand the minimum-spanning-tree of the terminals into paths in the graph.
+    let mut nodes = terminals.clone();
+    let mut edges = IndexMap::new();
+    for (i, j, _) in kruskal_indices(terminals.len(), &closure) {
+        let parents = &parents[i];
+        let cost = |n: &N| parents.get(n).map_or_else(Zero::zero, |&(_, c)| c);
+        let path = build_path(&terminals[j], parents);
+        for w in path.windows(2) {
+            let a = nodes.insert_full(w[0].clone()).0;
+            let b = nodes.insert_full(w[1].clone()).0;
+            edges
+                .entry((a.min(b), a.max(b)))
+                .or_insert_with(|| (a, b, cost(&w[1]) - cost(&w[0])));
+        }
+    }
+    // Build a minimum-spanning-tree of the paths, and remove non-terminal leaves.
+    let edges = edges.into_values().collect::<Vec<_>>();
+    let mut tree = kruskal_indices(nodes.len(), &edges).collect::<Vec<_>>();
+    let mut incident = vec![Vec::new(); nodes.len()];
+    for (e, &(a, b, _)) in tree.iter().enumerate() {
+        incident[a].push(e);
+        incident[b].push(e);
+    }
+    let mut degrees = incident.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut leaves = (terminals.len()..nodes.len())
+        .filter(|&n| degrees[n] == 1)
+        .collect::<Vec<_>>();
+    let mut removed = vec![false; tree.len()];
+    while let Some(leaf) = leaves.pop() {
+        let e = *incident[leaf].iter().find(|&&e| !removed[e]).unwrap();
+        removed[e] = true;
+        let (a, b, _) = tree[e];
+        let other = if a == leaf { b } else { a };
+        degrees[other] -= 1;
+        if degrees[other] == 1 && other >= terminals.len() {
+            leaves.push(other);
+        }
+    }
+    let mut removed = removed.into_iter();
+    tree.retain(|_| !removed.next().unwrap());
+    Some(
+        tree.into_iter()
+            .map(|(a, b, w)| (nodes[a].clone(), nodes[b].clone(), w))
+            .collect(),
+    )
+}
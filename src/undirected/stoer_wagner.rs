@@ -0,0 +1,136 @@
+//! Find a global minimum cut in an undirected weighted graph using the
+//! [Stoer–Wagner algorithm](https://en.wikipedia.org/wiki/Stoer–Wagner_algorithm).
+
+use indexmap::IndexSet;
+use num_traits::Zero;
+use std::hash::Hash;
+
+use crate::matrix::Matrix;
+
+/// Global minimum cut for nodes with integer indices. The nodes must have
+/// consecutive indices between 0 and `number_of_nodes`-1.
+///
+/// The function returns the nodes on one side of the cut, in increasing order,
+/// along with the total weight of the edges crossing the cut, or `None` if there
+/// are less than two nodes. Weights must be non-negative, several edges may link
+/// the same nodes, and loops are ignored.
+///
+/// # Panics
+///
+/// This function panics if a node is outside the range [0, `number_of_nodes`-1].
+pub fn stoer_wagner_indices<C>(
+    number_of_nodes: usize,
+    edges: &[(usize, usize, C)],
+) -> Option<(Vec<usize>, C)>
+where
+    C: Zero + Ord + Copy,
+{
+    if number_of_nodes < 2 {
+        return None;
+    }
+    let mut weights = Matrix::new(number_of_nodes, number_of_nodes, C::zero());
+    for &(a, b, w) in edges {
+        if a != b {
+            weights[(a, b)] = weights[(a, b)] + w;
+            weights[(b, a)] = weights[(b, a)] + w;
+        }
+    }
+    // Every remaining node stands for the group of original nodes merged into it.
+    let mut groups = (0..number_of_nodes).map(|n| vec![n]).collect::<Vec<_>>();
+    let mut remaining = (0..number_of_nodes).collect::<Vec<_>>();
+    let mut best: Option<(Vec<usize>, C)> = None;
+    while remaining.len() > 1 {
+        // Add the most tightly connected node until all of them have been added.
+        // The cut separating the last one from the others is the minimum cut
+        // between the last two nodes.
+        let mut connectivity = vec![C::zero(); number_of_nodes];
+        let mut added = vec![false; number_of_nodes];
+        let (mut previous, mut last) = (usize::MAX, usize::MAX);
+        for _ in 0..remaining.len() {
+            let mut next = usize::MAX;
+            for &n in &remaining {
+                if !added[n] && (next == usize::MAX || connectivity[n] > connectivity[next]) {
+                    next = n;
+                }
+            }
+            added[next] = true;
+            for &n in &remaining {
+                if !added[n] {
+                    connectivity[n] = connectivity[n] + weights[(next, n)];
+                }
+            }
+            previous = last;
+            last = next;
+        }
+        if best
+            .as_ref()
+            .map_or(true, |&(_, weight)| connectivity[last] < weight)
+        {
+            best = Some((groups[last].clone(), connectivity[last]));
+        }
+        // Merge the last two nodes.
+        let merged = std::mem::take(&mut groups[last]);
+        groups[previous].extend(merged);
+        remaining.retain(|&n| n != last);
+        for &n in &remaining {
+            if n != previous {
+                let weight = weights[(previous, n)] + weights[(last, n)];
+                weights[(previous, n)] = weight;
+                weights[(n, previous)] = weight;
+            }
+        }
+    }
+    best.map(|(mut side, weight)| {
+        side.sort_unstable();
+        (side, weight)
+    })
+}
+
+/// Find a global minimum cut, that is a partition of the nodes into two non-empty
+/// sets such that the total weight of the edges going from one set to the other is
+/// minimal. From a collection of weighted edges, return the nodes on one side of
+/// the cut along with the weight of the cut, or `None` if the edges reference
+/// less than two nodes.
+///
+/// Weights must be non-negative, several edges may link the same nodes, and loops
+/// are ignored. If the graph is not connected, a cut with a zero weight is returned.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::*;
+///
+/// // Two triangles linked by two light edges.
+/// let edges = [
+///     ('a', 'b', 3), ('b', 'c', 2), ('c', 'a', 4),
+///     ('d', 'e', 3), ('e', 'f', 5), ('f', 'd', 2),
+///     ('a', 'd', 1), ('c', 'f', 2),
+/// ];
+/// let (mut side, weight) = stoer_wagner(&edges).unwrap();
+/// side.sort();
+/// assert_eq!(weight, 3);
+/// assert!(side == [&'a', &'b', &'c'] || side == [&'d', &'e', &'f']);
+/// ```
+pub fn stoer_wagner<N, C>(edges: &[(N, N, C)]) -> Option<(Vec<&N>, C)>
+where
+    N: Hash + Eq,
+    C: Zero + Ord + Copy,
+{
+    let mut nodes = IndexSet::new();
+    let edges = edges
+        .iter()
+        .map(|(a, b, w)| {
+            let ia = nodes.insert_full(a).0;
+            let ib = nodes.insert_full(b).0;
+            (ia, ib, *w)
+        })
+        .collect::<Vec<_>>();
+    stoer_wagner_indices(nodes.len(), &edges).map(|(side, weight)| {
+        (
+            side.into_iter()
+                .map(|n| <&N>::clone(nodes.get_index(n).unwrap()))
+                .collect(),
+            weight,
+        )
+    })
+}
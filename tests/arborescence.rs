@@ -0,0 +1,96 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+// Find the weight of a minimum spanning arborescence rooted at 0 by trying
+// every choice of an incoming edge for every other node.
+fn brute_force(size: usize, edges: &[(usize, usize, i32)]) -> Option<i32> {
+    (1..size)
+        .map(|n| {
+            edges
+                .iter()
+                .filter(move |&&(a, b, _)| b == n && a != b)
+                .collect_vec()
+        })
+        .multi_cartesian_product()
+        .filter(|choice| {
+            // Every node must lead to the root.
+            (1..size).all(|n| {
+                let mut node = n;
+                for _ in 0..size {
+                    if node == 0 {
+                        return true;
+                    }
+                    node = choice[node - 1].0;
+                }
+                node == 0
+            })
+        })
+        .map(|choice| choice.iter().map(|&&(_, _, w)| w).sum())
+        .min()
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([13; 16]);
+    for _ in 0..300 {
+        let size = rng.gen_range(1..7);
+        let edges = (0..rng.gen_range(0..14))
+            .map(|_| {
+                (
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..size),
+                    rng.gen_range(-5..10),
+                )
+            })
+            .collect_vec();
+        let expected = if size == 1 {
+            Some(0)
+        } else {
+            brute_force(size, &edges)
+        };
+        let result = arborescence_indices(size, 0, &edges);
+        assert_eq!(
+            result.as_ref().map(|r| r.iter().map(|&(_, _, w)| w).sum()),
+            expected
+        );
+        if let Some(result) = result {
+            assert_eq!(result.len(), size - 1);
+            let reached =
+                bfs_reach(0, |&n| result.iter().filter(move |e| e.0 == n).map(|e| e.1)).count();
+            assert_eq!(reached, size);
+        }
+    }
+}
+
+#[test]
+fn nested_cycles() {
+    // Cycle a -> b -> c -> a is cheap, and contains cycle a -> b -> a once
+    // contracted edges are reweighted.
+    let edges = [
+        ("r", "a", 10),
+        ("r", "c", 12),
+        ("a", "b", 1),
+        ("b", "a", 1),
+        ("b", "c", 1),
+        ("c", "a", 2),
+        ("c", "b", 3),
+    ];
+    let result = arborescence(&"r", &edges).unwrap();
+    assert_eq!(result.iter().map(|&(_, _, w)| w).sum::<i32>(), 12);
+    assert_eq!(
+        result,
+        vec![(&"r", &"a", 10), (&"a", &"b", 1), (&"b", &"c", 1)]
+    );
+}
+
+#[test]
+fn unreachable_and_self_loops() {
+    assert_eq!(arborescence(&1, &[(1, 1, 3)]), Some(vec![]));
+    assert_eq!(arborescence(&1, &[(1, 2, 3), (3, 3, 0)]), None);
+    assert_eq!(
+        arborescence_indices(2, 1, &[(1, 0, 3), (0, 1, 1)]),
+        Some(vec![(1, 0, 3)])
+    );
+}
@@ -0,0 +1,198 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashSet;
+
+fn random_graph(rng: &mut XorShiftRng, size: usize) -> Vec<Vec<usize>> {
+    let mut graph = vec![Vec::new(); size];
+    for a in 0..size {
+        for b in a + 1..size {
+            // Add some parallel edges as well.
+            for _ in 0..[0, 0, 0, 0, 0, 1, 1, 2][rng.gen_range(0..8)] {
+                graph[a].push(b);
+                graph[b].push(a);
+            }
+        }
+    }
+    graph
+}
+
+// Check whether `a` and `b` are connected, ignoring vertex `removed` and
+// one instance of edge `removed_edge`.
+fn connected(
+    graph: &[Vec<usize>],
+    a: usize,
+    b: usize,
+    removed: Option<usize>,
+    removed_edge: Option<(usize, usize)>,
+) -> bool {
+    let reachable = bfs_reach(a, |&n| {
+        let mut neighbours = graph[n].clone();
+        if let Some((x, y)) = removed_edge {
+            let other = if n == x {
+                Some(y)
+            } else {
+                (n == y).then_some(x)
+            };
+            if let Some(other) = other {
+                let pos = neighbours.iter().position(|&m| m == other).unwrap();
+                neighbours.remove(pos);
+            }
+        }
+        neighbours.retain(|&m| Some(m) != removed);
+        neighbours
+    })
+    .collect::<HashSet<_>>();
+    reachable.contains(&b)
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    for _ in 0..100 {
+        let size = 9;
+        let graph = random_graph(&mut rng, size);
+        let nodes = (0..size).collect_vec();
+        let neighbours = |&n: &usize| graph[n].clone();
+        let pairs = (0..size).tuple_combinations::<(_, _)>().collect_vec();
+
+        let points = articulation_points(&nodes, neighbours);
+        for x in 0..size {
+            let separates = pairs.iter().any(|&(a, b)| {
+                a != x
+                    && b != x
+                    && connected(&graph, a, b, None, None)
+                    && !connected(&graph, a, b, Some(x), None)
+            });
+            assert_eq!(points.contains(&x), separates, "vertex {x}");
+        }
+
+        let bridges = bridges(&nodes, neighbours)
+            .into_iter()
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect::<HashSet<_>>();
+        let expected = pairs
+            .iter()
+            .filter(|&&(a, b)| {
+                graph[a].contains(&b) && !connected(&graph, a, b, None, Some((a, b)))
+            })
+            .copied()
+            .collect::<HashSet<_>>();
+        assert_eq!(bridges, expected);
+
+        let components = two_edge_connected_components(&nodes, neighbours);
+        assert_eq!(components.iter().map(HashSet::len).sum::<usize>(), size);
+        for &(a, b) in &pairs {
+            let together = components.iter().any(|c| c.contains(&a) && c.contains(&b));
+            let expected = connected(&graph, a, b, None, None)
+                && bridges
+                    .iter()
+                    .all(|&edge| connected(&graph, a, b, None, Some(edge)));
+            assert_eq!(together, expected, "vertices {a} and {b}");
+        }
+
+        // Two distinct vertices belong to a common biconnected component if
+        // they are adjacent, or if they are connected and no vertex separates them.
+        let components = biconnected_components(&nodes, neighbours);
+        for n in 0..size {
+            assert!(components.iter().any(|c| c.contains(&n)));
+        }
+        for &(a, b) in &pairs {
+            let count = components
+                .iter()
+                .filter(|c| c.contains(&a) && c.contains(&b))
+                .count();
+            let expected = graph[a].contains(&b)
+                || (connected(&graph, a, b, None, None)
+                    && (0..size)
+                        .all(|x| x == a || x == b || connected(&graph, a, b, Some(x), None)));
+            assert_eq!(count, usize::from(expected), "vertices {a} and {b}");
+        }
+    }
+}
+
+#[test]
+fn self_loops_and_parallel_edges() {
+    // 1 = 2 - 3, with a loop on 3 and an isolated vertex 4.
+    let neighbours = |&n: &u32| match n {
+        1 => vec![2, 2],
+        2 => vec![1, 1, 3],
+        3 => vec![2, 3],
+        _ => vec![],
+    };
+    let nodes = [1, 2, 3, 4];
+    assert_eq!(articulation_points(&nodes, neighbours), HashSet::from([2]));
+    assert_eq!(bridges(&nodes, neighbours).len(), 1);
+    let components = biconnected_components(&nodes, neighbours);
+    assert_eq!(components.len(), 3);
+    assert!(components.contains(&HashSet::from([1, 2])));
+    assert!(components.contains(&HashSet::from([2, 3])));
+    assert!(components.contains(&HashSet::from([4])));
+    let components = two_edge_connected_components(&nodes, neighbours);
+    assert_eq!(components.len(), 3);
+    assert!(components.contains(&HashSet::from([1, 2])));
+}
+
+#[test]
+fn asymmetric_neighbours() {
+    let mut rng = XorShiftRng::from_seed([4; 16]);
+    for _ in 0..100 {
+        let size = 9;
+        let graph = random_graph(&mut rng, size);
+        // Keep every edge on one side only, but on a random one. Parallel
+        // edges stay on the same side.
+        let mut directed = vec![Vec::new(); size];
+        for (a, b) in (0..size).tuple_combinations() {
+            let count = graph[a].iter().filter(|&&n| n == b).count();
+            let (from, to) = if rng.gen() { (a, b) } else { (b, a) };
+            directed[from].extend(std::iter::repeat(to).take(count));
+        }
+        let nodes = (0..size).collect_vec();
+        let symmetric = |&n: &usize| graph[n].clone();
+        let asymmetric = |&n: &usize| directed[n].clone();
+        assert_eq!(
+            articulation_points(&nodes, asymmetric),
+            articulation_points(&nodes, symmetric)
+        );
+        let sorted = |bridges: Vec<(usize, usize)>| {
+            bridges
+                .into_iter()
+                .map(|(a, b)| (a.min(b), a.max(b)))
+                .sorted()
+                .collect_vec()
+        };
+        assert_eq!(
+            sorted(bridges(&nodes, asymmetric)),
+            sorted(bridges(&nodes, symmetric))
+        );
+        let sorted = |components: Vec<HashSet<usize>>| {
+            components
+                .into_iter()
+                .map(|c| c.into_iter().sorted().collect_vec())
+                .sorted()
+                .collect_vec()
+        };
+        assert_eq!(
+            sorted(biconnected_components(&nodes, asymmetric)),
+            sorted(biconnected_components(&nodes, symmetric))
+        );
+        assert_eq!(
+            sorted(two_edge_connected_components(&nodes, asymmetric)),
+            sorted(two_edge_connected_components(&nodes, symmetric))
+        );
+    }
+}
+
+#[test]
+fn long_path() {
+    let size = 100_000;
+    let neighbours = |&n: &usize| {
+        [n.wrapping_sub(1), n + 1]
+            .into_iter()
+            .filter(move |&m| m < size)
+    };
+    assert_eq!(articulation_points(&[0], neighbours).len(), size - 2);
+    assert_eq!(bridges(&[0], neighbours).len(), size - 1);
+    assert_eq!(two_edge_connected_components(&[0], neighbours).len(), size);
+}
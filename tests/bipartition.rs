@@ -0,0 +1,66 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn grid() {
+    // A grid is bipartite, cells being split according to the parity
+    // of the sum of their coordinates.
+    let mut grid = Grid::new(5, 4);
+    grid.fill();
+    let (even, odd) = bipartition(&[(0, 0)], |&n| grid.neighbours(n)).unwrap();
+    assert_eq!(even.len(), 10);
+    assert_eq!(odd.len(), 10);
+    assert!(even.iter().all(|&(x, y)| (x + y) % 2 == 0));
+    assert!(odd.iter().all(|&(x, y)| (x + y) % 2 == 1));
+}
+
+#[test]
+fn isolated_and_self_loop() {
+    assert_eq!(
+        bipartition(&[1, 2], |_: &u32| Vec::new()),
+        Ok((vec![1, 2], vec![]))
+    );
+    assert_eq!(bipartition(&[1], |&n: &u32| vec![n]), Err(vec![1, 1]));
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([4; 16]);
+    for _ in 0..200 {
+        let size = 10;
+        let edges = (0..size)
+            .tuple_combinations::<(_, _)>()
+            .filter(|_| rng.gen_bool(0.15))
+            .collect_vec();
+        // Only give the edges in one direction.
+        let neighbours = |&n: &usize| edges.iter().filter(move |&&(a, _)| a == n).map(|&(_, b)| b);
+        let nodes = (0..size).collect_vec();
+        let odd_cycles = elementary_cycles(&nodes, |&n| {
+            edges
+                .iter()
+                .filter_map(move |&(a, b)| (a == n).then_some(b).or((b == n).then_some(a)))
+        })
+        .any(|c| c.len() % 2 == 0);
+        match bipartition(&nodes, neighbours) {
+            Ok((first, second)) => {
+                assert!(!odd_cycles);
+                assert_eq!(first.len() + second.len(), size);
+                assert!(edges
+                    .iter()
+                    .all(|(a, b)| first.contains(a) != first.contains(b)));
+            }
+            Err(cycle) => {
+                assert!(odd_cycles);
+                assert_eq!(cycle.len() % 2, 0);
+                assert_eq!(cycle.first(), cycle.last());
+                assert!(cycle.iter().skip(1).all_unique());
+                assert!(cycle
+                    .iter()
+                    .tuple_windows()
+                    .all(|(&a, &b)| { edges.contains(&(a, b)) || edges.contains(&(b, a)) }));
+            }
+        }
+    }
+}
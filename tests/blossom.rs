@@ -0,0 +1,193 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::{HashMap, HashSet};
+
+// Find the largest number of pairs and weight of a matching, as well as the largest
+// weight of a maximum cardinality matching, by trying every matching.
+fn brute_force(size: usize, edges: &HashMap<(usize, usize), i32>) -> (usize, i32, i32) {
+    fn explore(
+        node: usize,
+        size: usize,
+        used: &mut Vec<bool>,
+        edges: &HashMap<(usize, usize), i32>,
+        (pairs, weight): (usize, i32),
+        best: &mut (usize, i32, i32),
+    ) {
+        if node == size {
+            best.1 = best.1.max(weight);
+            if pairs > best.0 {
+                *best = (pairs, best.1, weight);
+            } else if pairs == best.0 {
+                best.2 = best.2.max(weight);
+            }
+            return;
+        }
+        if used[node] {
+            explore(node + 1, size, used, edges, (pairs, weight), best);
+            return;
+        }
+        explore(node + 1, size, used, edges, (pairs, weight), best);
+        used[node] = true;
+        for other in node + 1..size {
+            if let Some(&w) = edges.get(&(node, other)) {
+                if !used[other] {
+                    used[other] = true;
+                    explore(node + 1, size, used, edges, (pairs + 1, weight + w), best);
+                    used[other] = false;
+                }
+            }
+        }
+        used[node] = false;
+    }
+    let mut best = (0, 0, 0);
+    explore(0, size, &mut vec![false; size], edges, (0, 0), &mut best);
+    best
+}
+
+// Check that the pairs form a matching using existing edges, and return their weight.
+fn check_matching(pairs: &[(usize, usize)], edges: &HashMap<(usize, usize), i32>) -> i32 {
+    let mut seen = HashSet::new();
+    let mut weight = 0;
+    for &(a, b) in pairs {
+        assert!(seen.insert(a) && seen.insert(b));
+        weight += edges[&(a.min(b), a.max(b))];
+    }
+    weight
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([50; 16]);
+    for _ in 0..2000 {
+        let size = rng.gen_range(1..11);
+        let density = rng.gen_range(0.1..0.8);
+        let mut edges = HashMap::new();
+        for a in 0..size {
+            for b in a + 1..size {
+                if rng.gen_bool(density) {
+                    edges.insert((a, b), rng.gen_range(-5..20));
+                }
+            }
+        }
+        let (max_pairs, max_weight, max_cardinality_weight) = brute_force(size, &edges);
+        let neighbours = |&n: &usize| {
+            edges
+                .keys()
+                .filter_map(move |&(a, b)| (a == n).then_some(b))
+                .collect::<Vec<_>>()
+        };
+        let pairs = maximum_matching(&(0..size).collect::<Vec<_>>(), neighbours);
+        assert_eq!(pairs.len(), max_pairs);
+        check_matching(&pairs, &edges);
+        let weighted = edges
+            .iter()
+            .map(|(&(a, b), &w)| (a, b, w))
+            .collect::<Vec<_>>();
+        let (pairs, weight) = maximum_weight_matching(&weighted);
+        let pairs = pairs.into_iter().map(|(&a, &b)| (a, b)).collect::<Vec<_>>();
+        assert_eq!(weight, max_weight);
+        assert_eq!(check_matching(&pairs, &edges), weight);
+        let (pairs, weight) = maximum_weight_maximum_matching(&weighted);
+        let pairs = pairs.into_iter().map(|(&a, &b)| (a, b)).collect::<Vec<_>>();
+        assert_eq!(pairs.len(), max_pairs);
+        assert_eq!(weight, max_cardinality_weight);
+        assert_eq!(check_matching(&pairs, &edges), weight);
+    }
+}
+
+#[test]
+fn bipartite_graphs() {
+    // Compare with the Hopcroft-Karp algorithm on larger bipartite graphs.
+    let mut rng = XorShiftRng::from_seed([51; 16]);
+    for _ in 0..50 {
+        let size = rng.gen_range(1..60);
+        let density = rng.gen_range(0.0..0.1);
+        let edges = (0..size)
+            .map(|_| {
+                (size..2 * size)
+                    .filter(|_| rng.gen_bool(density))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let left = (0..size).collect::<Vec<_>>();
+        let expected = hopcroft_karp(&left, |&n| edges[n].clone()).len();
+        let pairs = maximum_matching(&left, |&n| if n < size { edges[n].clone() } else { vec![] });
+        assert_eq!(pairs.len(), expected);
+        let weighted = edges
+            .iter()
+            .enumerate()
+            .flat_map(|(a, bs)| bs.iter().map(move |&b| (a, b, 1)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            maximum_weight_matching(&weighted).1,
+            i32::try_from(expected).unwrap()
+        );
+    }
+}
+
+#[test]
+fn odd_cycles() {
+    // Two triangles linked by an edge, which need to be matched through a blossom.
+    let edges = [(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 6), (6, 4)];
+    let pairs = maximum_matching(&[1], |&n| {
+        edges
+            .iter()
+            .filter_map(move |&(a, b)| (a == n).then_some(b).or_else(|| (b == n).then_some(a)))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(pairs.len(), 3);
+    // A pentagon with a tail.
+    let pairs = maximum_matching(&[0], |&n: &u32| match n {
+        0 => vec![1],
+        1 => vec![2, 6],
+        n if n < 6 => vec![n + 1],
+        _ => vec![],
+    });
+    assert_eq!(pairs.len(), 3);
+}
+
+#[test]
+fn weighted_special_cases() {
+    assert_eq!(maximum_weight_matching::<u8, i32>(&[]), (vec![], 0));
+    assert_eq!(maximum_weight_matching(&[(1, 1, 5)]), (vec![], 0));
+    assert_eq!(maximum_weight_matching(&[(1, 2, -5)]), (vec![], 0));
+    assert_eq!(
+        maximum_weight_maximum_matching(&[(1, 2, -5)]),
+        (vec![(&1, &2)], -5)
+    );
+    // Only the heaviest parallel edge is considered.
+    assert_eq!(
+        maximum_weight_matching(&[(1, 2, 3), (2, 1, 7), (1, 2, 4)]),
+        (vec![(&1, &2)], 7)
+    );
+}
+
+#[test]
+fn larger_graphs() {
+    // The maximum cardinality matching does not depend on the weights.
+    let mut rng = XorShiftRng::from_seed([52; 16]);
+    for _ in 0..20 {
+        let size = rng.gen_range(20..100);
+        let edges = (0..rng.gen_range(size..3 * size))
+            .map(|_| {
+                (
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..size),
+                    rng.gen_range(-20..100),
+                )
+            })
+            .collect::<Vec<_>>();
+        let expected = maximum_matching(&(0..size).collect::<Vec<_>>(), |&n| {
+            edges
+                .iter()
+                .filter_map(move |&(a, b, _)| (a == n).then_some(b))
+                .collect::<Vec<_>>()
+        })
+        .len();
+        let (pairs, weight) = maximum_weight_maximum_matching(&edges);
+        assert_eq!(pairs.len(), expected);
+        let (_, max_weight) = maximum_weight_matching(&edges);
+        assert!(max_weight >= weight);
+    }
+}
@@ -0,0 +1,68 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+fn random_edges(rng: &mut XorShiftRng, size: usize, count: usize) -> Vec<(usize, usize, u32)> {
+    (0..count)
+        .map(|_| {
+            (
+                rng.gen_range(0..size),
+                rng.gen_range(0..size),
+                rng.gen_range(0..20),
+            )
+        })
+        .collect()
+}
+
+fn total_weight<N>(edges: &[(N, N, u32)]) -> u32 {
+    edges.iter().map(|&(_, _, w)| w).sum()
+}
+
+#[test]
+fn wikipedia() {
+    // Example from https://en.wikipedia.org/wiki/Kruskal's_algorithm
+    let edges = vec![
+        ('a', 'b', 3),
+        ('a', 'e', 1),
+        ('b', 'c', 5),
+        ('b', 'e', 4),
+        ('c', 'd', 2),
+        ('c', 'e', 6),
+        ('d', 'e', 7),
+    ];
+    assert_eq!(
+        boruvka(&edges).into_iter().sorted().collect_vec(),
+        kruskal(&edges).sorted().collect_vec()
+    );
+}
+
+#[test]
+fn random_forests() {
+    let mut rng = XorShiftRng::from_seed([9; 16]);
+    for _ in 0..100 {
+        let size = 30;
+        // Sparse graphs, so that the result is usually a forest.
+        let edges = random_edges(&mut rng, size, 30);
+        let expected = kruskal_indices(size, &edges).collect_vec();
+        let forest = boruvka_indices(size, &edges);
+        assert_eq!(forest.len(), expected.len());
+        assert_eq!(total_weight(&forest), total_weight(&expected));
+        #[cfg(feature = "rayon")]
+        assert_eq!(par_boruvka_indices(size, &edges), forest);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn large_parallel() {
+    let mut rng = XorShiftRng::from_seed([10; 16]);
+    let size = 10_000;
+    let edges = random_edges(&mut rng, size, 100_000);
+    let forest = par_boruvka_indices(size, &edges);
+    assert_eq!(forest, boruvka_indices(size, &edges));
+    assert_eq!(
+        total_weight(&forest),
+        total_weight(&kruskal_indices(size, &edges).collect_vec())
+    );
+}
@@ -0,0 +1,230 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::{HashMap, HashSet};
+
+type Edges = Vec<((usize, usize), i32, i32)>;
+
+// Build random edges, at most one for every pair of vertices.
+fn random_edges(rng: &mut XorShiftRng, size: usize) -> Edges {
+    let mut seen = HashSet::new();
+    (0..rng.gen_range(0..3 * size))
+        .map(|_| {
+            let lower = rng.gen_range(0..4);
+            (
+                (rng.gen_range(0..size), rng.gen_range(0..size)),
+                lower,
+                lower + rng.gen_range(0..6),
+            )
+        })
+        .filter(|&(edge, _, _)| seen.insert(edge))
+        .collect()
+}
+
+// Check that the flows respect the bounds, and return the inflow minus the outflow
+// of every vertex.
+fn balances(size: usize, edges: &Edges, flows: Vec<((usize, usize), i32)>) -> Vec<i32> {
+    let flows = flows.into_iter().collect::<HashMap<_, _>>();
+    let mut balances = vec![0; size];
+    for &((from, to), lower, upper) in edges {
+        let flow = flows.get(&(from, to)).copied().unwrap_or(0);
+        assert!(lower <= flow && flow <= upper);
+        balances[from] -= flow;
+        balances[to] += flow;
+    }
+    balances
+}
+
+// Check that `y` proves that the demands cannot be satisfied.
+fn check_certificate(demands: &[i32], edges: &Edges, y: &[usize]) {
+    let y = y.iter().copied().collect::<HashSet<_>>();
+    let demand = y.iter().map(|&n| demands[n]).sum::<i32>();
+    let mut available = 0;
+    for &((from, to), lower, upper) in edges {
+        if !y.contains(&from) && y.contains(&to) {
+            available += upper;
+        } else if y.contains(&from) && !y.contains(&to) {
+            available -= lower;
+        }
+    }
+    assert!(demand > available);
+}
+
+fn random_circulations<EK: EdmondsKarp<i32>>() {
+    let mut rng = XorShiftRng::from_seed([45; 16]);
+    let (mut feasible, mut infeasible) = (0, 0);
+    for _ in 0..300 {
+        let size = rng.gen_range(1..8);
+        let vertices = (0..size).collect::<Vec<_>>();
+        let edges = random_edges(&mut rng, size);
+        let mut demands = (0..size).map(|_| rng.gen_range(-3..4)).collect::<Vec<_>>();
+        demands[0] -= demands.iter().sum::<i32>();
+        match circulation::<_, _, _, _, EK>(
+            &vertices,
+            demands.iter().copied().enumerate(),
+            edges.clone(),
+        ) {
+            Ok(flows) => {
+                feasible += 1;
+                assert_eq!(balances(size, &edges, flows), demands);
+            }
+            Err(y) => {
+                infeasible += 1;
+                check_certificate(&demands, &edges, &y);
+            }
+        }
+    }
+    assert!(feasible > 20 && infeasible > 20);
+}
+
+#[test]
+fn random_circulations_sparse() {
+    random_circulations::<SparseCapacity<_>>();
+}
+
+#[test]
+fn random_circulations_dense() {
+    random_circulations::<DenseCapacity<_>>();
+}
+
+#[test]
+fn random_circulations_dinic() {
+    random_circulations::<Dinic<SparseCapacity<_>>>();
+}
+
+#[test]
+fn random_circulations_push_relabel() {
+    random_circulations::<PushRelabel<DenseCapacity<_>>>();
+}
+
+#[test]
+fn random_lower_bounds() {
+    let mut rng = XorShiftRng::from_seed([46; 16]);
+    let (mut feasible, mut infeasible) = (0, 0);
+    for _ in 0..300 {
+        let size = rng.gen_range(2..8);
+        let vertices = (0..size).collect::<Vec<_>>();
+        let edges = random_edges(&mut rng, size);
+        let sparse = max_flow_with_lower_bounds::<_, _, _, SparseCapacity<_>>(
+            &vertices,
+            &0,
+            &1,
+            edges.clone(),
+        );
+        let push_relabel = max_flow_with_lower_bounds::<_, _, _, PushRelabel<DenseCapacity<_>>>(
+            &vertices,
+            &0,
+            &1,
+            edges.clone(),
+        );
+        match (sparse, push_relabel) {
+            (Ok((flows, total)), Ok((other_flows, other_total))) => {
+                feasible += 1;
+                assert_eq!(total, other_total);
+                for flows in [flows, other_flows] {
+                    let balances = balances(size, &edges, flows);
+                    assert_eq!(balances[0], -total);
+                    assert_eq!(balances[1], total);
+                    assert!(balances[2..].iter().all(|&b| b == 0));
+                }
+                // Without lower bounds, the maximum flow cannot be smaller.
+                let (_, unbounded) = edmonds_karp_sparse(
+                    &vertices,
+                    &0,
+                    &1,
+                    edges
+                        .iter()
+                        .filter(|&&((from, to), _, _)| from != to)
+                        .map(|&(edge, _, upper)| (edge, upper)),
+                );
+                assert!(total <= unbounded);
+                if edges.iter().all(|&(_, lower, _)| lower == 0) {
+                    assert_eq!(total, unbounded);
+                }
+            }
+            (Err(y), Err(_)) => {
+                infeasible += 1;
+                assert!(!y.contains(&0) || y.contains(&1));
+                check_certificate(&vec![0; size], &edges, &y);
+            }
+            _ => panic!("backends disagree on feasibility"),
+        }
+    }
+    assert!(feasible > 20 && infeasible > 20);
+}
+
+#[test]
+fn parallel_edges() {
+    let edges = [
+        (('s', 't'), 1, 2),
+        (('s', 't'), 0, 3),
+        (('t', 's'), 2, 2),
+        (('s', 's'), 1, 1),
+    ];
+    assert_eq!(
+        max_flow_with_lower_bounds::<_, _, _, SparseCapacity<_>>(&['s', 't'], &'s', &'t', edges),
+        Ok((
+            vec![
+                (('s', 't'), 2),
+                (('s', 't'), 3),
+                (('t', 's'), 2),
+                (('s', 's'), 1)
+            ],
+            3
+        ))
+    );
+    assert_eq!(
+        circulation::<_, _, _, _, DenseCapacity<_>>(&['s', 't'], [], edges),
+        Ok(vec![(('s', 't'), 2), (('t', 's'), 2), (('s', 's'), 1)])
+    );
+}
+
+#[test]
+fn shift_rostering() {
+    // Workers 'a' and 'b' work between 1 and 2 slots, slots need between 1 and 2
+    // workers, and 'b' cannot work in the first slot. Every worker is given by the
+    // source, and every shift is sent to the sink.
+    let vertices = ["source", "a", "b", "slot1", "slot2", "slot3", "sink"];
+    let edges = vec![
+        (("source", "a"), 1, 2),
+        (("source", "b"), 1, 2),
+        (("a", "slot1"), 0, 1),
+        (("a", "slot2"), 0, 1),
+        (("a", "slot3"), 0, 1),
+        (("b", "slot2"), 0, 1),
+        (("b", "slot3"), 0, 1),
+        (("slot1", "sink"), 1, 2),
+        (("slot2", "sink"), 1, 2),
+        (("slot3", "sink"), 1, 2),
+    ];
+    let (flows, total) = max_flow_with_lower_bounds::<_, _, _, Dinic<SparseCapacity<_>>>(
+        &vertices,
+        &"source",
+        &"sink",
+        edges.clone(),
+    )
+    .unwrap();
+    assert_eq!(total, 4);
+    assert_eq!(
+        flows.iter().filter(|&&((from, _), _)| from == "a").count(),
+        2
+    );
+    // If 'b' cannot work in the second slot either and 'a' works only once, the
+    // first two slots cannot be covered.
+    let mut edges = edges;
+    edges.retain(|&(edge, _, _)| edge != ("b", "slot2"));
+    edges.retain(|&(edge, _, _)| edge != ("source", "a"));
+    edges.push((("source", "a"), 1, 1));
+    let y = max_flow_with_lower_bounds::<_, _, _, SparseCapacity<_>>(
+        &vertices, &"source", &"sink", edges,
+    )
+    .unwrap_err();
+    assert!(y.contains(&"slot1") && y.contains(&"slot2"));
+}
+
+#[test]
+#[should_panic]
+fn unbalanced_demands() {
+    let _ =
+        circulation::<_, _, _, _, SparseCapacity<_>>(&[1, 2], [(1, -2), (2, 1)], [((1, 2), 0, 5)]);
+}
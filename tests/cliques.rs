@@ -0,0 +1,72 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::{BTreeSet, HashSet};
+
+// Find all maximal cliques by checking every subset of vertices.
+fn brute_force(adjacent: &dyn Fn(usize, usize) -> bool, size: usize) -> Vec<BTreeSet<usize>> {
+    let is_clique = |s: &[usize]| s.iter().tuple_combinations().all(|(&a, &b)| adjacent(a, b));
+    (0..size)
+        .powerset()
+        .filter(|s| {
+            !s.is_empty()
+                && is_clique(s)
+                && (0..size).all(|n| {
+                    s.contains(&n) || !is_clique(&s.iter().copied().chain([n]).collect_vec())
+                })
+        })
+        .map(|s| s.into_iter().collect())
+        .sorted()
+        .collect()
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([8; 16]);
+    for _ in 0..50 {
+        let size = 10;
+        let edges = (0..size)
+            .tuple_combinations::<(_, _)>()
+            .filter(|_| rng.gen_bool(0.5))
+            .collect::<HashSet<_>>();
+        let adjacent = |a, b| edges.contains(&(a, b)) || edges.contains(&(b, a));
+        let neighbours = |&n: &usize| (0..size).filter(move |&m| adjacent(n, m)).collect_vec();
+        let nodes = (0..size).collect_vec();
+        let expected = brute_force(&adjacent, size);
+        let cliques = maximal_cliques(&nodes, neighbours)
+            .map(|c| c.into_iter().collect::<BTreeSet<_>>())
+            .sorted()
+            .collect_vec();
+        assert_eq!(cliques, expected);
+        let maximum = maximum_clique(&nodes, neighbours);
+        assert_eq!(
+            maximum.len(),
+            expected.iter().map(BTreeSet::len).max().unwrap()
+        );
+        assert!(expected.contains(&maximum.into_iter().collect()));
+    }
+}
+
+#[test]
+fn moon_moser() {
+    // The complement of 4 disjoint triangles has 3^4 maximal cliques of 4 vertices.
+    let neighbours = |&n: &usize| (0..12).filter(move |&m| m / 3 != n / 3);
+    let cliques = maximal_cliques(&[0], neighbours).collect_vec();
+    assert_eq!(cliques.len(), 81);
+    assert!(cliques.iter().all(|c| c.len() == 4));
+}
+
+#[test]
+fn isolated_and_self_loops() {
+    let cliques = maximal_cliques(
+        &[1, 2, 3],
+        |&n: &u32| if n < 3 { vec![n, 3 - n] } else { vec![n] },
+    )
+    .map(|c| c.into_iter().sorted().collect_vec())
+    .sorted()
+    .collect_vec();
+    assert_eq!(cliques, vec![vec![1, 2], vec![3]]);
+    assert!(maximum_clique(&[] as &[u32], |_| vec![]).is_empty());
+    assert_eq!(maximal_cliques(&[] as &[u32], |_| vec![]).count(), 0);
+}
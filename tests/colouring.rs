@@ -0,0 +1,103 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+fn check(graph: &[Vec<usize>], colours: &HashMap<usize, usize>) -> usize {
+    assert_eq!(colours.len(), graph.len());
+    for (a, neighbours) in graph.iter().enumerate() {
+        for &b in neighbours {
+            assert!(a == b || colours[&a] != colours[&b]);
+        }
+    }
+    colours.values().map(|&c| c + 1).max().unwrap_or(0)
+}
+
+// Find the chromatic number by trying every possible colouring.
+fn brute_force(graph: &[Vec<usize>]) -> usize {
+    (1..=graph.len())
+        .find(|&k| {
+            (0..graph.len())
+                .map(|_| 0..k)
+                .multi_cartesian_product()
+                .any(|colours| {
+                    graph
+                        .iter()
+                        .enumerate()
+                        .all(|(a, ns)| ns.iter().all(|&b| a == b || colours[a] != colours[b]))
+                })
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([6; 16]);
+    for _ in 0..50 {
+        let size = 7;
+        let graph = (0..size)
+            .map(|_| (0..size).filter(|_| rng.gen_bool(0.3)).collect_vec())
+            .collect_vec();
+        let nodes = (0..size).collect_vec();
+        let neighbours = |&n: &usize| graph[n].clone();
+        // `neighbours` is not symmetric, build the symmetric version to check results.
+        let mut symmetric = graph.clone();
+        for (a, ns) in graph.iter().enumerate() {
+            for &b in ns {
+                symmetric[b].push(a);
+            }
+        }
+        let chromatic_number = brute_force(&symmetric);
+        assert_eq!(
+            check(&symmetric, &exact_colouring(&nodes, neighbours)),
+            chromatic_number
+        );
+        assert!(check(&symmetric, &dsatur_colouring(&nodes, neighbours)) >= chromatic_number);
+        for order in [
+            ColouringOrder::Natural,
+            ColouringOrder::LargestFirst,
+            ColouringOrder::SmallestLast,
+        ] {
+            let colours = greedy_colouring(&nodes, neighbours, order);
+            assert!(check(&symmetric, &colours) >= chromatic_number);
+        }
+    }
+}
+
+#[test]
+fn bipartite() {
+    // DSATUR is exact for bipartite graphs, while a bad order can make the
+    // greedy algorithm use as many colours as half the number of vertices.
+    let neighbours =
+        |&(side, n): &(bool, usize)| (0..5).filter(move |&m| m != n).map(move |m| (!side, m));
+    let nodes = (0..5).flat_map(|n| [(false, n), (true, n)]).collect_vec();
+    let colours = dsatur_colouring(&nodes, neighbours);
+    assert_eq!(colours.values().max(), Some(&1));
+    let colours = greedy_colouring(&nodes, neighbours, ColouringOrder::Natural);
+    assert_eq!(colours.values().max(), Some(&4));
+}
+
+#[test]
+fn petersen() {
+    // Outer cycle 0-4, inner pentagram 5-9, and spokes.
+    let neighbours = |&n: &usize| {
+        if n < 5 {
+            vec![(n + 1) % 5, (n + 4) % 5, n + 5]
+        } else {
+            vec![(n + 2) % 5 + 5, (n + 3) % 5 + 5, n - 5]
+        }
+    };
+    let colours = exact_colouring(&[0], neighbours);
+    assert_eq!(colours.len(), 10);
+    assert_eq!(colours.values().max(), Some(&2));
+    let colours = greedy_colouring(&[0], neighbours, ColouringOrder::SmallestLast);
+    assert!(colours.values().max() <= Some(&3));
+}
+
+#[test]
+fn empty_and_self_loops() {
+    assert!(exact_colouring(&[] as &[u32], |_| vec![]).is_empty());
+    let colours = exact_colouring(&[1, 2], |&n: &u32| vec![n]);
+    assert_eq!(colours, HashMap::from([(1, 0), (2, 0)]));
+}
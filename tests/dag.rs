@@ -0,0 +1,75 @@
+use pathfinding::prelude::*;
+
+fn successors(n: &char) -> Vec<(char, i32)> {
+    match *n {
+        'a' => vec![('b', 3), ('c', 6)],
+        'b' => vec![('c', -4), ('d', 4), ('e', 11)],
+        'c' => vec![('d', 8), ('e', 2)],
+        'd' => vec![('e', -1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn shortest() {
+    let parents = dag_shortest_paths(&'a', successors).unwrap();
+    assert_eq!(parents.len(), 4);
+    assert_eq!(parents[&'c'], ('b', -1));
+    assert_eq!(parents[&'d'], ('b', 7));
+    assert_eq!(parents[&'e'], ('c', 1));
+    assert_eq!(build_path(&'e', &parents), vec!['a', 'b', 'c', 'e']);
+    assert!(dag_shortest_paths(&'e', successors).unwrap().is_empty());
+}
+
+#[test]
+fn longest() {
+    let parents = dag_longest_paths(&'a', successors).unwrap();
+    assert_eq!(parents[&'c'], ('a', 6));
+    assert_eq!(parents[&'d'], ('c', 14));
+    assert_eq!(parents[&'e'], ('b', 14));
+    assert_eq!(build_path(&'d', &parents), vec!['a', 'c', 'd']);
+}
+
+#[test]
+fn cycle() {
+    let result = dag_longest_paths(&0, |&n| vec![((n + 1) % 3, 1)]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn schedule() {
+    // Example from https://en.wikipedia.org/wiki/Critical_path_method
+    let successors = |&t: &char| match t {
+        'a' => vec!['b', 'c'],
+        'b' => vec!['d'],
+        'c' => vec!['d', 'e'],
+        'd' => vec!['f'],
+        'e' => vec!['g'],
+        'f' => vec!['g'],
+        _ => vec![],
+    };
+    let duration = |&t: &char| match t {
+        'a' => 3,
+        'b' => 4,
+        'c' => 2,
+        'd' => 5,
+        'e' => 1,
+        'f' => 2,
+        _ => 1,
+    };
+    let schedule = critical_path(&['a'], successors, duration).unwrap();
+    assert_eq!(schedule.duration, 15);
+    assert_eq!(schedule.critical_path, vec!['a', 'b', 'd', 'f', 'g']);
+    assert_eq!(
+        schedule.times[&'c'],
+        TaskTimes {
+            earliest_start: 3,
+            latest_start: 5,
+            slack: 2
+        }
+    );
+    assert_eq!(schedule.times[&'e'].earliest_start, 5);
+    assert_eq!(schedule.times[&'e'].slack, 8);
+    assert_eq!(schedule.times[&'g'].latest_start, 14);
+    assert!(critical_path(&['a'], |_| vec!['a'], duration).is_err());
+}
@@ -0,0 +1,72 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+#[test]
+fn disconnected() {
+    let (caps, total) = dinic_sparse(&[1, 2, 3], &1, &3, [((1, 2), 4), ((3, 2), 5)]);
+    assert!(caps.is_empty());
+    assert_eq!(total, 0);
+}
+
+#[test]
+fn bipartite_matching() {
+    // Source 0, sink 1, left vertices 2..102 and right vertices 102..202. Every
+    // left vertex i can be matched with right vertices i+100 and i+101.
+    let vertices = (0..202).collect::<Vec<_>>();
+    let mut caps = Vec::new();
+    for i in 2..102 {
+        caps.push(((0, i), 1));
+        caps.push(((i + 100, 1), 1));
+        caps.push(((i, i + 100), 1));
+        if i + 101 < 202 {
+            caps.push(((i, i + 101), 1));
+        }
+    }
+    let (flows, total) = dinic_sparse(&vertices, &0, &1, caps);
+    assert_eq!(total, 100);
+    assert_eq!(flows.len(), 300);
+}
+
+#[test]
+fn omit_detailed_flows() {
+    let mut network = Dinic::<SparseCapacity<i32>>::new(3, 0, 2);
+    network.set_capacity(0, 1, 2);
+    network.set_capacity(1, 2, 3);
+    network.omit_detailed_flows();
+    assert_eq!(network.augment(), (vec![], 2));
+}
+
+#[test]
+fn same_as_edmonds_karp() {
+    let mut rng = XorShiftRng::from_seed([41; 16]);
+    for _ in 0..200 {
+        let size = rng.gen_range(2..12);
+        let vertices = (0..size).collect::<Vec<_>>();
+        let caps = (0..rng.gen_range(0..40))
+            .map(|_| {
+                let from = rng.gen_range(0..size);
+                let to = rng.gen_range(0..size);
+                ((from, to), rng.gen_range(1..10))
+            })
+            .filter(|((from, to), _)| from != to)
+            .collect::<HashMap<_, _>>();
+        let (_, expected) = edmonds_karp_sparse(&vertices, &0, &1, caps.clone());
+        for (flows, total) in [
+            dinic_dense(&vertices, &0, &1, caps.clone()),
+            dinic_sparse(&vertices, &0, &1, caps.clone()),
+        ] {
+            assert_eq!(total, expected);
+            let mut balance = vec![0; size];
+            for ((from, to), flow) in flows {
+                assert!(flow > 0 && flow <= caps[&(from, to)]);
+                balance[from] -= flow;
+                balance[to] += flow;
+            }
+            assert_eq!(balance[0], -total);
+            assert_eq!(balance[1], total);
+            assert!(balance[2..].iter().all(|&b| b == 0));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashSet;
+
+// Merge sets naively by relabelling all the elements of one of them.
+fn naive_union(labels: &mut [usize], a: usize, b: usize) -> bool {
+    let (la, lb) = (labels[a], labels[b]);
+    for l in labels.iter_mut().filter(|l| **l == lb) {
+        *l = la;
+    }
+    la != lb
+}
+
+#[test]
+fn random_unions() {
+    let mut rng = XorShiftRng::from_seed([12; 16]);
+    for rollback in [false, true] {
+        let size = 50;
+        let mut sets = if rollback {
+            UnionFind::with_rollback(size)
+        } else {
+            UnionFind::new(size)
+        };
+        let mut labels = (0..size).collect_vec();
+        for _ in 0..100 {
+            let (a, b) = (rng.gen_range(0..size), rng.gen_range(0..size));
+            assert_eq!(sets.union(a, b), naive_union(&mut labels, a, b));
+            let (c, d) = (rng.gen_range(0..size), rng.gen_range(0..size));
+            assert_eq!(sets.same_set(c, d), labels[c] == labels[d]);
+            assert_eq!(
+                sets.set_size(c),
+                labels.iter().filter(|&&l| l == labels[c]).count()
+            );
+            assert_eq!(sets.sets_count(), labels.iter().unique().count());
+        }
+        let expected = (0..size)
+            .into_group_map_by(|&n| labels[n])
+            .into_values()
+            .sorted()
+            .collect_vec();
+        assert_eq!(sets.sets(), expected);
+    }
+}
+
+#[test]
+fn rollback() {
+    let mut sets = UnionFind::with_rollback(6);
+    sets.union(0, 1);
+    let snapshot = sets.snapshot();
+    sets.union(1, 2);
+    sets.union(3, 4);
+    let inner = sets.snapshot();
+    sets.union(2, 4);
+    assert_eq!(sets.set_size(0), 5);
+    sets.rollback(inner);
+    assert_eq!(sets.sets(), vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    let added = sets.add();
+    sets.union(added, 5);
+    sets.rollback(snapshot);
+    assert_eq!(
+        sets.sets(),
+        vec![vec![0, 1], vec![2], vec![3], vec![4], vec![5], vec![6]]
+    );
+    assert_eq!(sets.sets_count(), 6);
+}
+
+#[test]
+#[should_panic]
+fn rollback_unsupported() {
+    let sets = UnionFind::new(3);
+    let _ = sets.snapshot();
+}
+
+#[test]
+fn keyed() {
+    let mut sets = DisjointSet::with_rollback();
+    assert!(sets.insert(&"a"));
+    assert!(!sets.insert(&"a"));
+    assert!(sets.union(&"a", &"b"));
+    let snapshot = sets.snapshot();
+    assert!(sets.union(&"c", &"b"));
+    assert!(!sets.union(&"a", &"c"));
+    assert_eq!(sets.len(), 3);
+    assert_eq!(sets.set_size(&"c"), 3);
+    assert_eq!(sets.set_size(&"d"), 1);
+    assert!(sets.same_set(&"d", &"d"));
+    assert!(!sets.same_set(&"a", &"d"));
+    assert_eq!(sets.find(&"d"), None);
+    let root = *sets.find(&"a").unwrap();
+    assert_eq!(sets.find(&"c"), Some(&root));
+    sets.rollback(snapshot);
+    assert!(!sets.same_set(&"a", &"c"));
+    assert_eq!(sets.sets_count(), 2);
+    let all = sets.sets();
+    assert!(all.contains(&HashSet::from(["a", "b"])));
+    assert!(all.contains(&HashSet::from(["c"])));
+}
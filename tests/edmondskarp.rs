@@ -1,4 +1,6 @@
+use pathfinding::directed::dinic::Dinic;
 use pathfinding::directed::edmonds_karp::*;
+use pathfinding::directed::push_relabel::PushRelabel;
 use std::collections::HashMap;
 
 /// Return a list of edges with their capacities.
@@ -39,13 +41,38 @@ fn check_wikipedia_result(flows: EKFlows<char, i32>) {
     assert_eq!(total, 5);
 }
 
-fn wikipedia_example<EK: EdmondsKarp<i32>>() {
-    check_wikipedia_result(edmonds_karp::<_, _, _, EK>(
+// Check that the flows are a maximum flow, without requiring specific paths
+// since some algorithms may choose other ones.
+fn check_wikipedia_flows(flows: EKFlows<char, i32>) {
+    let (caps, total) = flows;
+    assert_eq!(total, 5);
+    let capacities = successors_wikipedia()
+        .into_iter()
+        .collect::<HashMap<(char, char), i32>>();
+    let mut balance = HashMap::new();
+    for ((from, to), flow) in caps {
+        assert!(flow > 0 && flow <= capacities[&(from, to)]);
+        *balance.entry(from).or_insert(0) -= flow;
+        *balance.entry(to).or_insert(0) += flow;
+    }
+    assert_eq!(balance[&'A'], -5);
+    assert_eq!(balance[&'G'], 5);
+    assert!(balance
+        .iter()
+        .all(|(&n, &b)| b == 0 || n == 'A' || n == 'G'));
+}
+
+fn wikipedia_flows<EK: EdmondsKarp<i32>>() -> EKFlows<char, i32> {
+    edmonds_karp::<_, _, _, EK>(
         &"ABCDEFGH".chars().collect::<Vec<_>>(),
         &'A',
         &'G',
         successors_wikipedia(),
-    ));
+    )
+}
+
+fn wikipedia_example<EK: EdmondsKarp<i32>>() {
+    check_wikipedia_result(wikipedia_flows::<EK>());
 }
 
 #[test]
@@ -58,6 +85,26 @@ fn wikipedia_example_sparse() {
     wikipedia_example::<SparseCapacity<_>>();
 }
 
+#[test]
+fn wikipedia_example_dinic_dense() {
+    wikipedia_example::<Dinic<DenseCapacity<_>>>();
+}
+
+#[test]
+fn wikipedia_example_dinic_sparse() {
+    wikipedia_example::<Dinic<SparseCapacity<_>>>();
+}
+
+#[test]
+fn wikipedia_example_push_relabel_dense() {
+    check_wikipedia_flows(wikipedia_flows::<PushRelabel<DenseCapacity<_>>>());
+}
+
+#[test]
+fn wikipedia_example_push_relabel_sparse() {
+    check_wikipedia_flows(wikipedia_flows::<PushRelabel<SparseCapacity<_>>>());
+}
+
 fn wikipedia_progressive_example<EK: EdmondsKarp<i32>>() {
     let successors = successors_wikipedia();
     let size = successors.len();
@@ -151,6 +198,26 @@ fn modified_sparse() {
     modified::<SparseCapacity<i32>>()
 }
 
+#[test]
+fn modified_dinic_dense() {
+    modified::<Dinic<DenseCapacity<i32>>>()
+}
+
+#[test]
+fn modified_dinic_sparse() {
+    modified::<Dinic<SparseCapacity<i32>>>()
+}
+
+#[test]
+fn modified_push_relabel_dense() {
+    modified::<PushRelabel<DenseCapacity<i32>>>()
+}
+
+#[test]
+fn modified_push_relabel_sparse() {
+    modified::<PushRelabel<SparseCapacity<i32>>>()
+}
+
 #[test]
 #[should_panic]
 fn empty() {
@@ -169,3 +236,51 @@ fn unknown_source() {
 fn unknown_sink() {
     edmonds_karp_dense(&[1, 2, 3], &1, &4, Vec::<((i32, i32), i32)>::new());
 }
+
+fn wikipedia_min_cut<EK: EdmondsKarp<i32>>() {
+    let (side, edges) = min_cut::<_, _, _, EK>(
+        &"ABCDEFGH".chars().collect::<Vec<_>>(),
+        &'A',
+        &'G',
+        successors_wikipedia(),
+    );
+    assert_eq!(side, vec!['A', 'B', 'C', 'E']);
+    assert_eq!(
+        edges,
+        vec![(('A', 'D'), 3), (('C', 'D'), 1), (('E', 'G'), 1)]
+    );
+}
+
+#[test]
+fn wikipedia_min_cut_dense() {
+    wikipedia_min_cut::<DenseCapacity<_>>();
+}
+
+#[test]
+fn wikipedia_min_cut_sparse() {
+    wikipedia_min_cut::<SparseCapacity<_>>();
+}
+
+#[test]
+fn wikipedia_min_cut_dinic() {
+    wikipedia_min_cut::<Dinic<SparseCapacity<_>>>();
+}
+
+#[test]
+fn wikipedia_min_cut_push_relabel() {
+    wikipedia_min_cut::<PushRelabel<DenseCapacity<_>>>();
+}
+
+#[test]
+fn modified_min_cut() {
+    let mut ek = SparseCapacity::new(4, 0, 3);
+    ek.set_capacity(0, 1, 2);
+    ek.set_capacity(1, 3, 5);
+    ek.set_capacity(0, 2, 4);
+    ek.set_capacity(2, 3, 3);
+    assert_eq!(ek.augment().1, 5);
+    assert_eq!(ek.min_cut(), (vec![0, 2], vec![((0, 1), 2), ((2, 3), 3)]));
+    ek.set_capacity(2, 3, 1);
+    assert_eq!(ek.augment().1, 3);
+    assert_eq!(ek.min_cut(), (vec![0, 2], vec![((0, 1), 2), ((2, 3), 1)]));
+}
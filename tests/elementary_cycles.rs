@@ -0,0 +1,84 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+// Find all elementary cycles by brute force, each one starting with its
+// smallest node.
+fn brute_force(succs: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    fn extend(succs: &[Vec<usize>], path: &mut Vec<usize>, cycles: &mut Vec<Vec<usize>>) {
+        for &s in &succs[*path.last().unwrap()] {
+            if s == path[0] {
+                cycles.push(path.iter().copied().chain([s]).collect());
+            } else if s > path[0] && !path.contains(&s) {
+                path.push(s);
+                extend(succs, path, cycles);
+                path.pop();
+            }
+        }
+    }
+    let mut cycles = Vec::new();
+    for start in 0..succs.len() {
+        extend(succs, &mut vec![start], &mut cycles);
+    }
+    cycles
+}
+
+#[test]
+fn complete_graph() {
+    let nodes = (0..5).collect_vec();
+    let cycles = elementary_cycles(&nodes, |&n| (0..5).filter(move |&m| m != n)).collect_vec();
+    // Sum over k of C(5, k) * (k-1)!
+    assert_eq!(cycles.len(), 10 + 20 + 30 + 24);
+    assert!(cycles.iter().all_unique());
+    let short = elementary_cycles(&nodes, |&n| (0..5).filter(move |&m| m != n))
+        .max_length(3)
+        .collect_vec();
+    assert_eq!(short.len(), 10 + 20);
+}
+
+#[test]
+fn self_loops_and_acyclic() {
+    let cycles =
+        elementary_cycles(&[1, 2], |&n| if n < 3 { vec![n, 3] } else { vec![] }).collect_vec();
+    assert_eq!(cycles, vec![vec![1, 1], vec![2, 2]]);
+    assert_eq!(
+        elementary_cycles(&[1], |&n| (n < 5).then_some(n + 1)).count(),
+        0
+    );
+    assert_eq!(
+        elementary_cycles(&[1], |&n| vec![n, n])
+            .max_length(0)
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    for _ in 0..50 {
+        let size = 9;
+        let succs = (0..size)
+            .map(|_| (0..size).filter(|_| rng.gen_bool(0.3)).collect_vec())
+            .collect_vec();
+        let nodes = (0..size).collect_vec();
+        let expected = brute_force(&succs).into_iter().sorted().collect_vec();
+        let cycles = elementary_cycles(&nodes, |&n| succs[n].clone())
+            .sorted()
+            .collect_vec();
+        assert_eq!(cycles, expected);
+        for max_length in 1..4 {
+            let cycles = elementary_cycles(&nodes, |&n| succs[n].clone())
+                .max_length(max_length)
+                .sorted()
+                .collect_vec();
+            let expected = expected
+                .iter()
+                .filter(|c| c.len() <= max_length + 1)
+                .cloned()
+                .collect_vec();
+            assert_eq!(cycles, expected);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::{HashMap, HashSet};
+
+// Check that the paths and cycles are simple, and that they recompose the flows.
+fn check(source: usize, sink: usize, flows: &[((usize, usize), i32)], total: i32) {
+    let (paths, cycles) = decompose_flows(&source, &sink, flows);
+    let mut recomposed = HashMap::new();
+    for (path, flow) in &paths {
+        assert!(*flow > 0);
+        assert_eq!(path.first(), Some(&source));
+        assert_eq!(path.last(), Some(&sink));
+        assert_eq!(path.iter().collect::<HashSet<_>>().len(), path.len());
+        for edge in path.windows(2) {
+            *recomposed.entry((edge[0], edge[1])).or_insert(0) += flow;
+        }
+    }
+    assert_eq!(paths.iter().map(|(_, flow)| flow).sum::<i32>(), total);
+    for (cycle, flow) in &cycles {
+        assert!(*flow > 0);
+        assert!(cycle.len() > 2);
+        assert_eq!(cycle.first(), cycle.last());
+        assert_eq!(
+            cycle[1..].iter().collect::<HashSet<_>>().len(),
+            cycle.len() - 1
+        );
+        for edge in cycle.windows(2) {
+            *recomposed.entry((edge[0], edge[1])).or_insert(0) += flow;
+        }
+    }
+    let flows = flows
+        .iter()
+        .copied()
+        .filter(|&(_, flow)| flow > 0)
+        .collect::<HashMap<_, _>>();
+    assert_eq!(recomposed, flows);
+}
+
+#[test]
+fn maximum_flows() {
+    let mut rng = XorShiftRng::from_seed([48; 16]);
+    for _ in 0..200 {
+        let size = rng.gen_range(2..12);
+        let vertices = (0..size).collect::<Vec<_>>();
+        let caps = (0..rng.gen_range(0..4 * size))
+            .map(|_| {
+                (
+                    (rng.gen_range(0..size), rng.gen_range(0..size)),
+                    rng.gen_range(1..10),
+                )
+            })
+            .filter(|&((from, to), _)| from != to)
+            .collect::<HashMap<_, _>>();
+        let (flows, total) = edmonds_karp_sparse(&vertices, &0, &1, caps.clone());
+        check(0, 1, &flows, total);
+        let (flows, total) =
+            edmonds_karp::<_, _, _, PushRelabel<DenseCapacity<_>>>(&vertices, &0, &1, caps);
+        check(0, 1, &flows, total);
+    }
+}
+
+#[test]
+fn cycles() {
+    let flows = [
+        ((0, 2), 3),
+        ((2, 3), 5),
+        ((3, 2), 2),
+        ((3, 1), 3),
+        ((1, 4), 1),
+        ((4, 5), 1),
+        ((5, 1), 1),
+        ((6, 6), 1),
+        ((6, 7), 0),
+    ];
+    let (paths, cycles) = decompose_flows(&0, &1, &flows);
+    assert_eq!(paths, vec![(vec![0, 2, 3, 1], 3)]);
+    assert_eq!(
+        cycles,
+        vec![(vec![2, 3, 2], 2), (vec![1, 4, 5, 1], 1), (vec![6, 6], 1)]
+    );
+    check(0, 1, &flows[..7], 3);
+}
+
+#[test]
+fn without_net_flow() {
+    // Flow going through the source and the sink without any net flow.
+    let flows = [
+        (('s', 't'), 2),
+        (('t', 's'), 2),
+        (('t', 'a'), 1),
+        (('a', 't'), 1),
+    ];
+    assert_eq!(
+        decompose_flows(&'s', &'t', &flows),
+        (
+            vec![],
+            vec![(vec!['s', 't', 's'], 2), (vec!['t', 'a', 't'], 1)]
+        )
+    );
+    assert_eq!(
+        decompose_flows(&'s', &'s', &flows),
+        (
+            vec![],
+            vec![(vec!['s', 't', 's'], 2), (vec!['t', 'a', 't'], 1)]
+        )
+    );
+}
+
+#[test]
+fn source_receiving_flow() {
+    let flows = [((0, 2), 3), ((2, 0), 1), ((2, 1), 2)];
+    let (paths, cycles) = decompose_flows(&0, &1, &flows);
+    assert_eq!(paths, vec![(vec![0, 2, 1], 2)]);
+    assert_eq!(cycles, vec![(vec![0, 2, 0], 1)]);
+}
+
+#[test]
+#[should_panic]
+fn not_conserved() {
+    decompose_flows(&0, &1, &[((0, 2), 3), ((2, 1), 2)]);
+}
+
+#[test]
+#[should_panic]
+fn reversed() {
+    decompose_flows(&0, &1, &[((1, 0), 3)]);
+}
@@ -0,0 +1,162 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+#[test]
+fn single_source_and_sink() {
+    // Same result as a direct call to Edmonds-Karp.
+    let edges = [
+        ('A', 'B', 3),
+        ('A', 'D', 3),
+        ('B', 'C', 4),
+        ('C', 'A', 3),
+        ('C', 'D', 1),
+        ('C', 'E', 2),
+        ('D', 'E', 2),
+        ('D', 'F', 6),
+        ('E', 'B', 1),
+        ('E', 'G', 1),
+        ('F', 'G', 9),
+    ];
+    let mut network = FlowNetwork::new();
+    for (from, to, capacity) in edges {
+        network.add_edge(from, to, capacity);
+    }
+    network.add_source('A', None);
+    network.add_sink('G', None);
+    assert_eq!(network.max_flow::<SparseCapacity<_>>().1, 5);
+    network.add_source('A', Some(4));
+    assert_eq!(network.max_flow::<DenseCapacity<_>>().1, 4);
+    network.add_source('A', None);
+    network.set_vertex_capacity('D', 2);
+    let (flows, total) = network.max_flow::<Dinic<SparseCapacity<_>>>();
+    assert_eq!(total, 3);
+    let flows = flows.into_iter().collect::<HashMap<_, _>>();
+    assert_eq!(flows.get(&('D', 'F')), Some(&2));
+}
+
+#[test]
+fn multiple_sources_and_sinks() {
+    let mut network = FlowNetwork::new();
+    network.add_edge(1, 3, 10);
+    network.add_edge(2, 3, 10);
+    network.add_edge(3, 4, 10);
+    network.add_edge(3, 5, 10);
+    network.add_source(1, Some(2));
+    network.add_source(2, Some(3));
+    network.add_sink(4, Some(1));
+    network.add_sink(5, None);
+    let (mut flows, total) = network.max_flow::<SparseCapacity<_>>();
+    assert_eq!(total, 5);
+    flows.sort_unstable();
+    assert_eq!(
+        flows,
+        vec![((1, 3), 2), ((2, 3), 3), ((3, 4), 1), ((3, 5), 4)]
+    );
+    network.set_vertex_capacity(3, 4);
+    assert_eq!(network.max_flow::<PushRelabel<DenseCapacity<_>>>().1, 4);
+    network.set_vertex_capacity(5, 2);
+    assert_eq!(network.max_flow::<SparseCapacity<_>>().1, 3);
+}
+
+#[test]
+fn no_source() {
+    let mut network = FlowNetwork::new();
+    network.add_edge('a', 'b', 1);
+    network.add_sink('b', None);
+    assert_eq!(network.max_flow::<SparseCapacity<i32>>(), (vec![], 0));
+}
+
+#[test]
+fn random_networks() {
+    let mut rng = XorShiftRng::from_seed([47; 16]);
+    for _ in 0..200 {
+        let size = rng.gen_range(2..10);
+        let mut network = FlowNetwork::new();
+        let mut capacities = HashMap::new();
+        for _ in 0..rng.gen_range(0..3 * size) {
+            let (from, to) = (rng.gen_range(0..size), rng.gen_range(0..size));
+            let capacity = rng.gen_range(1..10);
+            network.add_edge(from, to, capacity);
+            if from != to {
+                *capacities.entry((from, to)).or_insert(0) += capacity;
+            }
+        }
+        let mut supplies = HashMap::new();
+        let mut demands = HashMap::new();
+        let mut vertex_capacities = HashMap::new();
+        for vertex in 0..size {
+            match rng.gen_range(0..4) {
+                0 => {
+                    let supply = rng.gen_range(0..15);
+                    network.add_source(vertex, Some(supply));
+                    supplies.insert(vertex, supply);
+                }
+                1 => {
+                    let demand = rng.gen_range(0..15);
+                    network.add_sink(vertex, Some(demand));
+                    demands.insert(vertex, demand);
+                }
+                _ => (),
+            }
+            if rng.gen_bool(0.3) {
+                let capacity = rng.gen_range(0..15);
+                network.set_vertex_capacity(vertex, capacity);
+                vertex_capacities.insert(vertex, capacity);
+            }
+        }
+        let (flows, total) = network.max_flow::<SparseCapacity<_>>();
+        assert_eq!(network.max_flow::<Dinic<SparseCapacity<_>>>().1, total);
+        assert_eq!(network.max_flow::<PushRelabel<DenseCapacity<_>>>().1, total);
+        // Check that capacities, supplies and demands are respected.
+        let mut inflows = vec![0; size];
+        let mut outflows = vec![0; size];
+        for ((from, to), flow) in flows {
+            assert!(flow > 0 && flow <= capacities[&(from, to)]);
+            outflows[from] += flow;
+            inflows[to] += flow;
+        }
+        let mut supplied = 0;
+        let mut absorbed = 0;
+        for vertex in 0..size {
+            let supply = outflows[vertex] - inflows[vertex];
+            if supply > 0 {
+                assert!(supply <= supplies[&vertex]);
+                supplied += supply;
+            } else if supply < 0 {
+                assert!(-supply <= demands[&vertex]);
+                absorbed -= supply;
+            }
+            if let Some(&capacity) = vertex_capacities.get(&vertex) {
+                assert!(inflows[vertex].max(outflows[vertex]) <= capacity);
+            }
+        }
+        assert_eq!(supplied, total);
+        assert_eq!(absorbed, total);
+        // Check the value of the flow against a manual transformation.
+        let mut manual = Vec::new();
+        let leaving = |n: usize| {
+            if vertex_capacities.contains_key(&n) {
+                n + size
+            } else {
+                n
+            }
+        };
+        for (&(from, to), &capacity) in &capacities {
+            manual.push(((leaving(from), to), capacity));
+        }
+        for (&vertex, &capacity) in &vertex_capacities {
+            manual.push(((vertex, vertex + size), capacity));
+        }
+        for (&vertex, &supply) in &supplies {
+            manual.push(((2 * size, vertex), supply));
+        }
+        for (&vertex, &demand) in &demands {
+            manual.push(((leaving(vertex), 2 * size + 1), demand));
+        }
+        let vertices = (0..2 * size + 2).collect::<Vec<_>>();
+        let (_, expected) = edmonds_karp_sparse(&vertices, &(2 * size), &(2 * size + 1), manual);
+        assert_eq!(total, expected);
+    }
+}
@@ -0,0 +1,103 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+// Weight of the cut separating the nodes whose bit is set in `side`.
+fn cut_weight(edges: &[(usize, usize, i32)], side: u32) -> i32 {
+    edges
+        .iter()
+        .filter(|&&(a, b, _)| (side >> a & 1) != (side >> b & 1))
+        .map(|&(_, _, w)| w)
+        .sum()
+}
+
+fn random_graphs<EK: EdmondsKarp<i32>>() {
+    let mut rng = XorShiftRng::from_seed([47; 16]);
+    for _ in 0..100 {
+        let size = rng.gen_range(2..8);
+        let mut edges = (0..rng.gen_range(0..3 * size))
+            .map(|_| {
+                (
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..10),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Make sure that every node is present.
+        edges.extend((1..size).map(|n| (n - 1, n, rng.gen_range(0..3))));
+        let tree = GomoryHuTree::new::<EK>(&edges);
+        assert_eq!(tree.edges().len(), size - 1);
+        for a in 0..size {
+            assert_eq!(tree.min_cut(&a, &a), None);
+            for b in a + 1..size {
+                let expected = (0..1 << size)
+                    .filter(|side| side >> a & 1 == 1 && side >> b & 1 == 0)
+                    .map(|side| cut_weight(&edges, side))
+                    .min()
+                    .unwrap();
+                assert_eq!(tree.min_cut(&a, &b), Some(expected));
+                assert_eq!(tree.min_cut(&b, &a), Some(expected));
+            }
+        }
+        // Removing any edge of the tree splits the nodes along a cut of the
+        // same weight.
+        for (&a, &b, weight) in tree.edges() {
+            let tree_edges = tree
+                .edges()
+                .into_iter()
+                .filter(|&(&x, &y, _)| (x, y) != (a, b))
+                .map(|(&x, &y, _)| (x, y))
+                .collect::<Vec<_>>();
+            let components = connected_components(&(0..size).collect::<Vec<_>>(), |&n| {
+                tree_edges
+                    .iter()
+                    .filter_map(move |&(x, y)| {
+                        (x == n).then_some(y).or_else(|| (y == n).then_some(x))
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let side = components
+                .iter()
+                .find(|c| c.contains(&a))
+                .unwrap()
+                .iter()
+                .fold(0, |s, n| s | 1 << n);
+            assert_eq!(cut_weight(&edges, side), weight);
+        }
+        // The lightest edge of the tree is a global minimum cut.
+        let lightest = tree.edges().into_iter().map(|(_, _, w)| w).min().unwrap();
+        assert_eq!(stoer_wagner(&edges).unwrap().1, lightest);
+    }
+}
+
+#[test]
+fn random_graphs_sparse() {
+    random_graphs::<SparseCapacity<_>>();
+}
+
+#[test]
+fn random_graphs_dense() {
+    random_graphs::<DenseCapacity<_>>();
+}
+
+#[test]
+fn random_graphs_dinic() {
+    random_graphs::<Dinic<SparseCapacity<_>>>();
+}
+
+#[test]
+fn random_graphs_push_relabel() {
+    random_graphs::<PushRelabel<DenseCapacity<_>>>();
+}
+
+#[test]
+fn empty_and_disconnected() {
+    let tree = GomoryHuTree::new::<SparseCapacity<i32>>(&[]);
+    assert!(tree.edges().is_empty());
+    assert_eq!(tree.min_cut(&1, &2), None);
+    let tree = GomoryHuTree::new::<SparseCapacity<_>>(&[(1, 2, 4), (3, 4, 2), (2, 2, 5)]);
+    assert_eq!(tree.min_cut(&1, &2), Some(4));
+    assert_eq!(tree.min_cut(&3, &4), Some(2));
+    assert_eq!(tree.min_cut(&1, &4), Some(0));
+}
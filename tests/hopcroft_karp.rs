@@ -0,0 +1,89 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashSet;
+
+#[test]
+fn empty() {
+    let matching = hopcroft_karp(&[1, 2], |_| Vec::<char>::new());
+    assert!(matching.is_empty());
+    assert_eq!(matching.len(), 0);
+    assert_eq!(matching.pairs(), vec![]);
+    assert_eq!(matching.left_mate(&1), None);
+    assert_eq!(matching.right_mate(&'a'), None);
+    assert_eq!(matching.vertex_cover(), (vec![], vec![]));
+    assert_eq!(matching.independent_set(), (vec![1, 2], vec![]));
+}
+
+#[test]
+fn perfect_matching() {
+    // Every row is linked to the same and to the next column.
+    let matching = hopcroft_karp(&(0..100).collect::<Vec<_>>(), |&n| [n, (n + 1) % 100]);
+    assert_eq!(matching.len(), 100);
+    for (l, r) in matching.pairs() {
+        assert!(r == l || r == (l + 1) % 100);
+        assert_eq!(matching.left_mate(&l), Some(&r));
+        assert_eq!(matching.right_mate(&r), Some(&l));
+    }
+    let (left, right) = matching.vertex_cover();
+    assert_eq!(left.len() + right.len(), 100);
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([49; 16]);
+    for _ in 0..200 {
+        let rows = rng.gen_range(1..10);
+        let columns = rng.gen_range(rows..12);
+        let density = rng.gen_range(0.0..0.6);
+        let edges = (0..rows)
+            .map(|_| {
+                (0..columns)
+                    .filter(|_| rng.gen_bool(density))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let matching = hopcroft_karp(&(0..rows).collect::<Vec<_>>(), |&l| edges[l].clone());
+        // The size of the matching is the weight found by the Kuhn-Munkres algorithm
+        // when weights are 0 or 1.
+        let mut weights = Matrix::new(rows, columns, 0);
+        for (l, successors) in edges.iter().enumerate() {
+            for &r in successors {
+                weights[(l, r)] = 1;
+            }
+        }
+        let (expected, _) = kuhn_munkres(&weights);
+        assert_eq!(matching.len(), expected as usize);
+        let pairs = matching.pairs();
+        assert_eq!(pairs.len(), matching.len());
+        assert_eq!(
+            pairs.iter().map(|&(_, r)| r).collect::<HashSet<_>>().len(),
+            pairs.len()
+        );
+        for &(l, r) in &pairs {
+            assert!(edges[l].contains(&r));
+        }
+        // Check the vertex cover and the independent set.
+        let (cover_left, cover_right) = matching.vertex_cover();
+        assert_eq!(cover_left.len() + cover_right.len(), matching.len());
+        let (independent_left, independent_right) = matching.independent_set();
+        let present = edges.iter().flatten().collect::<HashSet<_>>().len();
+        assert_eq!(
+            independent_left.len() + independent_right.len(),
+            rows + present - matching.len()
+        );
+        for (l, successors) in edges.iter().enumerate() {
+            for r in successors {
+                assert!(cover_left.contains(&l) || cover_right.contains(r));
+                assert!(!independent_left.contains(&l) || !independent_right.contains(r));
+            }
+        }
+    }
+}
+
+#[test]
+fn different_types() {
+    let matching = hopcroft_karp(&["ab", "a", "bc"], |s| s.chars().collect::<Vec<_>>());
+    assert_eq!(matching.len(), 3);
+    assert_eq!(matching.pairs(), vec![("ab", 'b'), ("a", 'a'), ("bc", 'c')]);
+}
@@ -0,0 +1,180 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashSet;
+
+#[test]
+fn assignment() {
+    // Compare with the Kuhn-Munkres algorithm on random assignment problems.
+    let mut rng = XorShiftRng::from_seed([44; 16]);
+    for _ in 0..50 {
+        let size = rng.gen_range(1..8);
+        let weights =
+            Matrix::square_from_vec((0..size * size).map(|_| rng.gen_range(-50..50)).collect())
+                .unwrap();
+        let (expected, _) = kuhn_munkres_min(&weights);
+        // Source is 0, sink is 1, rows are 2.. and columns follow.
+        let vertices = (0..2 * size + 2).collect::<Vec<_>>();
+        let mut edges = Vec::new();
+        for i in 0..size {
+            edges.push(((0, i + 2), 1, 0));
+            edges.push(((i + size + 2, 1), 1, 0));
+            for j in 0..size {
+                edges.push(((i + 2, j + size + 2), 1, weights[(i, j)]));
+            }
+        }
+        let (flows, total, cost) = min_cost_max_flow(&vertices, &0, &1, edges);
+        assert_eq!(total, size as i32);
+        assert_eq!(cost, expected);
+        assert_eq!(flows.len(), 3 * size);
+    }
+}
+
+#[test]
+fn transportation() {
+    // Two warehouses with supplies 20 and 30, three shops with demands 10, 25 and 15.
+    let vertices = ["s", "w1", "w2", "a", "b", "c", "t"];
+    let edges = [
+        (("s", "w1"), 20, 0),
+        (("s", "w2"), 30, 0),
+        (("w1", "a"), 20, 8),
+        (("w1", "b"), 20, 6),
+        (("w1", "c"), 20, 10),
+        (("w2", "a"), 30, 9),
+        (("w2", "b"), 30, 12),
+        (("w2", "c"), 30, 13),
+        (("a", "t"), 10, 0),
+        (("b", "t"), 25, 0),
+        (("c", "t"), 15, 0),
+    ];
+    let (flows, total, cost) = min_cost_max_flow(&vertices, &"s", &"t", edges);
+    assert_eq!(total, 50);
+    assert_eq!(cost, 465);
+    let flow = |from, to| {
+        flows
+            .iter()
+            .find(|&&(edge, _)| edge == (from, to))
+            .map_or(0, |&(_, f)| f)
+    };
+    assert_eq!(flow("w1", "a") + flow("w2", "a"), 10);
+    assert_eq!(flow("w1", "b") + flow("w2", "b"), 25);
+    assert_eq!(flow("w1", "c") + flow("w2", "c"), 15);
+}
+
+#[test]
+fn limited_value() {
+    let vertices = ['s', 'a', 't'];
+    let edges = [
+        (('s', 'a'), 5, 1),
+        (('a', 't'), 2, 1),
+        (('a', 't'), 2, 3),
+        (('s', 't'), 1, 10),
+    ];
+    assert_eq!(
+        min_cost_flow(&vertices, &'s', &'t', edges, 0),
+        Some((vec![], 0, 0))
+    );
+    assert_eq!(
+        min_cost_flow(&vertices, &'s', &'t', edges, 3),
+        Some((
+            vec![(('s', 'a'), 3), (('a', 't'), 2), (('a', 't'), 1)],
+            3,
+            8
+        ))
+    );
+    assert_eq!(
+        min_cost_max_flow(&vertices, &'s', &'t', edges),
+        (
+            vec![
+                (('s', 'a'), 4),
+                (('a', 't'), 2),
+                (('a', 't'), 2),
+                (('s', 't'), 1)
+            ],
+            5,
+            22
+        )
+    );
+    assert_eq!(min_cost_flow(&vertices, &'s', &'t', edges, 6), None);
+}
+
+#[test]
+fn negative_costs() {
+    // The expensive direct edge must be compensated by the negative one.
+    let vertices = [0, 1, 2, 3];
+    let edges = [
+        ((0, 1), 1, 4),
+        ((1, 3), 1, -3),
+        ((0, 2), 1, 1),
+        ((2, 3), 1, 1),
+        ((2, 1), 1, -2),
+    ];
+    assert_eq!(
+        min_cost_flow(&vertices, &0, &3, edges, 1),
+        Some((vec![((1, 3), 1), ((0, 2), 1), ((2, 1), 1)], 1, -4))
+    );
+    let (_, total, cost) = min_cost_max_flow(&vertices, &0, &3, edges);
+    assert_eq!((total, cost), (2, 3));
+}
+
+#[test]
+#[should_panic]
+fn negative_cycle() {
+    let edges = [
+        ((0, 1), 1, 1),
+        ((1, 2), 1, -3),
+        ((2, 1), 1, 1),
+        ((2, 3), 1, 1),
+    ];
+    min_cost_max_flow(&[0, 1, 2, 3], &0, &3, edges);
+}
+
+#[test]
+fn disconnected() {
+    let edges = [((0, 1), 3, 1), ((2, 1), 3, 1)];
+    assert_eq!(min_cost_max_flow(&[0, 1, 2], &0, &2, edges), (vec![], 0, 0));
+    assert_eq!(
+        min_cost_flow(&[0, 1, 2], &0, &0, edges, 0),
+        Some((vec![], 0, 0))
+    );
+}
+
+#[test]
+fn same_value_as_edmonds_karp() {
+    let mut rng = XorShiftRng::from_seed([45; 16]);
+    for _ in 0..100 {
+        let size = rng.gen_range(2..10);
+        let vertices = (0..size).collect::<Vec<_>>();
+        let mut seen = HashSet::new();
+        let edges = (0..rng.gen_range(0..30))
+            .map(|_| {
+                let from = rng.gen_range(0..size);
+                let to = rng.gen_range(0..size);
+                ((from, to), rng.gen_range(1..10), rng.gen_range(0..10))
+            })
+            .filter(|&((from, to), _, _)| from != to && seen.insert((from, to)))
+            .collect::<Vec<_>>();
+        let (flows, total, cost) = min_cost_max_flow(&vertices, &0, &1, edges.clone());
+        let (_, expected) = edmonds_karp_sparse(
+            &vertices,
+            &0,
+            &1,
+            edges.iter().map(|&(edge, capacity, _)| (edge, capacity)),
+        );
+        assert_eq!(total, expected);
+        let mut balance = vec![0; size];
+        let mut edges = edges.into_iter();
+        let mut expected_cost = 0;
+        for ((from, to), flow) in flows {
+            let (_, capacity, cost) = edges.find(|&(edge, _, _)| edge == (from, to)).unwrap();
+            assert!(flow <= capacity);
+            expected_cost += flow * cost;
+            balance[from] -= flow;
+            balance[to] += flow;
+        }
+        assert_eq!(cost, expected_cost);
+        assert_eq!(balance[0], -total);
+        assert_eq!(balance[1], total);
+        assert!(balance[2..].iter().all(|&b| b == 0));
+    }
+}
@@ -0,0 +1,68 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::HashSet;
+
+#[test]
+fn grid() {
+    // Moving vertically costs more than moving horizontally.
+    let mut grid = Grid::new(4, 3);
+    grid.fill();
+    let tree = prim(&(0, 0), |&(x, y)| {
+        grid.neighbours((x, y))
+            .into_iter()
+            .map(move |(nx, ny)| ((nx, ny), if ny == y { 1 } else { 2 }))
+    });
+    assert_eq!(tree.len(), 11);
+    assert_eq!(tree.iter().map(|&(_, _, w)| w).sum::<i32>(), 3 * 3 + 2 * 2);
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([11; 16]);
+    for _ in 0..100 {
+        let size = 20;
+        let edges = (0..60)
+            .map(|_| {
+                (
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..20),
+                )
+            })
+            .collect_vec();
+        let neighbours = |&n: &usize| {
+            edges.iter().filter_map(move |&(a, b, w)| {
+                (a == n).then_some((b, w)).or((b == n).then_some((a, w)))
+            })
+        };
+        let tree = prim(&0, neighbours);
+        // Only keep the edges of the component containing 0 to compare with Kruskal.
+        let component = bfs_reach(0, |&n| neighbours(&n).map(|(m, _)| m)).collect::<HashSet<_>>();
+        let expected = kruskal_indices(
+            size,
+            &edges
+                .iter()
+                .filter(|(a, _, _)| component.contains(a))
+                .copied()
+                .collect_vec(),
+        )
+        .collect_vec();
+        assert_eq!(tree.len(), component.len() - 1);
+        assert_eq!(
+            tree.iter().map(|&(_, _, w)| w).sum::<u32>(),
+            expected.iter().map(|&(_, _, w)| w).sum::<u32>()
+        );
+        let mut seen = HashSet::from([0]);
+        for &(parent, child, _) in &tree {
+            assert!(seen.contains(&parent));
+            assert!(seen.insert(child));
+        }
+    }
+}
+
+#[test]
+fn isolated() {
+    assert!(prim(&1, |_: &u32| Vec::<(u32, u32)>::new()).is_empty());
+}
@@ -0,0 +1,114 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+use std::collections::{HashMap, HashSet};
+
+// Find the weight of a minimum Steiner tree by computing the minimum-spanning-tree
+// of every set of vertices containing the terminals.
+fn brute_force(size: usize, edges: &[(usize, usize, u32)], terminals: &[usize]) -> Option<u32> {
+    let others = (0..size).filter(|n| !terminals.contains(n)).collect_vec();
+    others
+        .into_iter()
+        .powerset()
+        .filter_map(|extra| {
+            let vertices = terminals
+                .iter()
+                .chain(&extra)
+                .copied()
+                .collect::<HashSet<_>>();
+            let induced = edges
+                .iter()
+                .filter(|(a, b, _)| vertices.contains(a) && vertices.contains(b))
+                .copied()
+                .collect_vec();
+            let tree = kruskal_indices(size, &induced).collect_vec();
+            (tree.len() + 1 == vertices.len().max(1)).then(|| tree.iter().map(|&(_, _, w)| w).sum())
+        })
+        .min()
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([14; 16]);
+    let mut found = 0;
+    for _ in 0..100 {
+        let size = 8;
+        let mut edges = Vec::new();
+        for (a, b) in (0..size).tuple_combinations() {
+            if rng.gen_bool(0.4) {
+                edges.push((a, b, rng.gen_range(1..10)));
+            }
+        }
+        let weights = edges
+            .iter()
+            .flat_map(|&(a, b, w)| [((a, b), w), ((b, a), w)])
+            .collect::<HashMap<_, _>>();
+        let neighbours = |&n: &usize| {
+            edges.iter().filter_map(move |&(a, b, w)| {
+                (a == n).then_some((b, w)).or((b == n).then_some((a, w)))
+            })
+        };
+        let terminals = (0..size).filter(|_| rng.gen_bool(0.4)).collect_vec();
+        let expected = brute_force(size, &edges, &terminals);
+        match steiner_tree(&terminals, neighbours) {
+            None => assert_eq!(expected, None),
+            Some(tree) => {
+                found += 1;
+                let weight = tree.iter().map(|&(_, _, w)| w).sum::<u32>();
+                let optimal = expected.unwrap();
+                assert!(optimal <= weight && weight < 2 * optimal.max(1));
+                for &(a, b, w) in &tree {
+                    assert_eq!(weights[&(a, b)], w);
+                }
+                // The result must be a tree whose leaves are terminals.
+                let vertices = tree.iter().flat_map(|&(a, b, _)| [a, b]).counts();
+                if terminals.len() > 1 {
+                    assert_eq!(vertices.len(), tree.len() + 1);
+                    assert!(terminals.iter().all(|t| vertices.contains_key(t)));
+                } else {
+                    assert!(tree.is_empty());
+                }
+                assert!(vertices
+                    .iter()
+                    .all(|(n, &c)| c > 1 || terminals.contains(n)));
+                let reached = bfs_reach(tree.first().map_or(0, |e| e.0), |&n| {
+                    tree.iter().filter_map(move |&(a, b, _)| {
+                        (a == n).then_some(b).or((b == n).then_some(a))
+                    })
+                })
+                .count();
+                assert_eq!(reached, vertices.len().max(1));
+            }
+        }
+    }
+    assert!(found > 50);
+}
+
+#[test]
+fn grid() {
+    // Connecting the corners of a grid needs 3 sides of the square.
+    let mut grid = Grid::new(5, 5);
+    grid.fill();
+    let corners = [(0, 0), (4, 0), (0, 4), (4, 4)];
+    let tree = steiner_tree(&corners, |&n| {
+        grid.neighbours(n).into_iter().map(|m| (m, 1))
+    })
+    .unwrap();
+    assert_eq!(tree.len(), 12);
+}
+
+#[test]
+fn trivial() {
+    let neighbours = |&n: &u32| vec![(n + 1, 1)];
+    assert_eq!(steiner_tree(&[], neighbours), Some(vec![]));
+    assert_eq!(steiner_tree(&[3], neighbours), Some(vec![]));
+    assert_eq!(
+        steiner_tree(&[1, 3], neighbours),
+        Some(vec![(1, 2, 1), (2, 3, 1)])
+    );
+    assert_eq!(
+        steiner_tree(&[1, 2], |_: &u32| vec![]),
+        None::<Vec<(u32, u32, u32)>>
+    );
+}
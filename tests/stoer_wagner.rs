@@ -0,0 +1,78 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+// Weight of the cut separating the nodes whose bit is set in `side`.
+fn cut_weight(edges: &[(usize, usize, u32)], side: u32) -> u32 {
+    edges
+        .iter()
+        .filter(|&&(a, b, _)| (side >> a & 1) != (side >> b & 1))
+        .map(|&(_, _, w)| w)
+        .sum()
+}
+
+#[test]
+fn small_graphs() {
+    assert_eq!(stoer_wagner::<char, u32>(&[]), None);
+    assert_eq!(stoer_wagner(&[('a', 'a', 3)]), None);
+    assert_eq!(stoer_wagner(&[('a', 'b', 3)]), Some((vec![&'b'], 3)));
+    assert_eq!(
+        stoer_wagner(&[('a', 'b', 3), ('b', 'a', 2), ('b', 'b', 1)]),
+        Some((vec![&'b'], 5))
+    );
+    assert_eq!(
+        stoer_wagner(&[('a', 'b', 3), ('c', 'd', 2)]).map(|(_, w)| w),
+        Some(0)
+    );
+}
+
+#[test]
+fn wikipedia_example() {
+    let edges = [
+        (1, 2, 2),
+        (1, 5, 3),
+        (2, 3, 3),
+        (2, 5, 2),
+        (2, 6, 2),
+        (3, 4, 4),
+        (3, 7, 2),
+        (4, 7, 2),
+        (4, 8, 2),
+        (5, 6, 3),
+        (6, 7, 1),
+        (7, 8, 3),
+    ];
+    let (mut side, weight) = stoer_wagner(&edges).unwrap();
+    side.sort_unstable();
+    assert_eq!(weight, 4);
+    assert!(side == [&1, &2, &5, &6] || side == [&3, &4, &7, &8]);
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([48; 16]);
+    for _ in 0..300 {
+        let size = rng.gen_range(2..9);
+        let mut edges = (0..rng.gen_range(0..3 * size))
+            .map(|_| {
+                (
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..size),
+                    rng.gen_range(0..10),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Make sure that every node is present.
+        edges.extend((1..size).map(|n| (n - 1, n, rng.gen_range(0..3))));
+        let expected = (1..(1 << size) - 1)
+            .map(|side| cut_weight(&edges, side))
+            .min()
+            .unwrap();
+        let (side, weight) = stoer_wagner_indices(size, &edges).unwrap();
+        assert_eq!(weight, expected);
+        assert!(!side.is_empty() && side.len() < size);
+        let side = side.into_iter().fold(0, |s, n| s | 1 << n);
+        assert_eq!(cut_weight(&edges, side), weight);
+        assert_eq!(stoer_wagner(&edges).unwrap().1, weight);
+    }
+}
@@ -119,3 +119,43 @@ fn loops() {
     c.sort();
     assert_eq!(c, vec![vec![0], vec![42]]);
 }
+
+#[test]
+fn long_chain() {
+    // The graph exploration must not overflow the stack.
+    let c = strongly_connected_components(&[0], |&n| (n < 100_000).then_some(n + 1));
+    assert_eq!(c.len(), 100_001);
+    let c = strongly_connected_component(&0, |&n| vec![(n + 1) % 100_000]);
+    assert_eq!(c.len(), 100_000);
+}
+
+#[test]
+fn condensation_graph() {
+    let c = condensation(&(0..=15).collect::<Vec<_>>(), successors);
+    assert_eq!(c.components.len(), EXPECTED.len());
+    for (n, expected) in SCC.iter() {
+        let mut component = c.components[c.component_index[n]].clone();
+        component.sort_unstable();
+        assert_eq!(&component, expected);
+    }
+    let index = |n: usize| c.component_index[&n];
+    let mut edges = c
+        .successors
+        .iter()
+        .enumerate()
+        .flat_map(|(from, succs)| succs.iter().map(move |&to| (from, to)))
+        .collect::<Vec<_>>();
+    assert!(edges.iter().all(|&(from, to)| from < to));
+    edges.sort_unstable();
+    let mut expected = vec![
+        (index(0), index(5)),
+        (index(0), index(9)),
+        (index(5), index(6)),
+        (index(5), index(13)),
+        (index(6), index(15)),
+        (index(9), index(13)),
+        (index(13), index(15)),
+    ];
+    expected.sort_unstable();
+    assert_eq!(edges, expected);
+}
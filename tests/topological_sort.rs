@@ -1,6 +1,9 @@
 use itertools::Itertools;
 use pathfinding::directed::topological_sort::topological_sort as tsort;
-use pathfinding::directed::topological_sort::topological_sort_into_groups;
+use pathfinding::directed::topological_sort::{
+    all_topological_sorts, topological_sort_by, topological_sort_by_key,
+    topological_sort_into_groups, topological_sort_with_cycle,
+};
 use rand::prelude::SliceRandom;
 use rand::rngs;
 
@@ -123,3 +126,75 @@ fn tsig_self_edge() {
         Err((vec![vec![0], vec![1, 2]], vec![3]))
     );
 }
+
+#[test]
+fn cycle() {
+    let succs: &[&[usize]] = &[&[1, 2], &[3], &[4], &[], &[5], &[2]];
+    let nodes = (0..succs.len()).collect_vec();
+    let cycle = topological_sort_with_cycle(&nodes, |&n| succs[n].iter().copied()).unwrap_err();
+    let start = cycle.iter().position(|&n| n == 2).unwrap();
+    assert_eq!(cycle.len(), 4);
+    assert_eq!(cycle[0], cycle[3]);
+    assert_eq!(
+        cycle[start..].iter().chain(&cycle[1..=start]).collect_vec(),
+        vec![&2, &4, &5, &2]
+    );
+    assert!(tsort(&nodes, |&n| succs[n].iter().copied()).is_err());
+    assert_eq!(
+        topological_sort_with_cycle(&[7], |_| vec![7]),
+        Err(vec![7, 7])
+    );
+}
+
+#[test]
+fn long_chain() {
+    // The graph exploration must not overflow the stack.
+    let result = topological_sort_with_cycle(&[0], |&n| (n < 100_000).then_some(n + 1));
+    assert_eq!(result, Ok((0..=100_000).collect_vec()));
+    let result = tsort(&[0], |&n| if n < 100_000 { vec![n + 1] } else { vec![0] });
+    assert!(result.is_err());
+}
+
+#[test]
+fn by_priority() {
+    // Divisors must come before the numbers they divide.
+    let successors = |&n: &usize| (2..).map(move |m| m * n).take_while(|&p| p <= 12);
+    let nodes = (1..=12).rev().collect_vec();
+    assert_eq!(
+        topological_sort_by(&nodes, successors, Ord::cmp),
+        Ok(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+    );
+    assert_eq!(
+        topological_sort_by_key(&nodes, successors, |&n| std::cmp::Reverse(n)),
+        Ok(vec![1, 11, 7, 5, 3, 9, 2, 10, 6, 4, 12, 8])
+    );
+}
+
+#[test]
+fn by_priority_cycle() {
+    let succs: &[&[usize]] = &[&[1], &[2], &[3], &[2, 4], &[]];
+    assert_eq!(
+        topological_sort_by(&[4, 3, 2, 1, 0], |&n| succs[n].iter().copied(), Ord::cmp),
+        Err((vec![0, 1], vec![4, 3, 2]))
+    );
+}
+
+#[test]
+fn all_sorts() {
+    let succs: &[&[usize]] = &[&[1, 2], &[3], &[3], &[], &[]];
+    let nodes = (0..succs.len()).collect_vec();
+    let orders = all_topological_sorts(&nodes, |&n| succs[n].iter().copied()).collect_vec();
+    assert_eq!(orders.len(), 10);
+    assert_eq!(orders[0], vec![0, 1, 2, 3, 4]);
+    assert_eq!(orders[9], vec![4, 0, 2, 1, 3]);
+    assert!(orders.iter().all_unique());
+    for order in orders {
+        let position = |n: usize| order.iter().position(|&m| m == n).unwrap();
+        assert!((0..succs.len()).all(|n| succs[n].iter().all(|&s| position(n) < position(s))));
+    }
+    assert_eq!(all_topological_sorts(&[0], |&n| vec![n]).count(), 0);
+    assert_eq!(
+        all_topological_sorts(&[] as &[usize], |_| vec![]).collect_vec(),
+        vec![Vec::<usize>::new()]
+    );
+}
@@ -0,0 +1,72 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn diamond() {
+    let succs: &[&[usize]] = &[&[1, 2, 3], &[3], &[3], &[]];
+    let closure = transitive_closure(&[0], |&n| succs[n].iter().copied());
+    assert_eq!(
+        closure.edges().sorted().collect_vec(),
+        vec![(&0, &1), (&0, &2), (&0, &3), (&1, &3), (&2, &3)]
+    );
+    assert!(!closure.reaches(&1, &2));
+    assert!(!closure.reaches(&0, &4));
+    let reduction = transitive_reduction(&[0], |&n| succs[n].iter().copied()).unwrap();
+    assert_eq!(
+        reduction.into_iter().sorted().collect_vec(),
+        vec![(0, 1), (0, 2), (1, 3), (2, 3)]
+    );
+}
+
+#[test]
+fn cycles() {
+    let succs: &[&[usize]] = &[&[1], &[2], &[1, 3], &[3]];
+    let closure = transitive_closure(&[0], |&n| succs[n].iter().copied());
+    assert!(!closure.reaches(&0, &0));
+    assert!(closure.reaches(&1, &1));
+    assert!(closure.reaches(&2, &1));
+    assert!(closure.reaches(&3, &3));
+    assert_eq!(closure.reachable_from(&1).collect_vec(), vec![&1, &2, &3]);
+    assert!(transitive_reduction(&[0], |&n| succs[n].iter().copied()).is_err());
+    assert_eq!(transitive_reduction(&[3], |_| vec![3]), Err(3));
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    for _ in 0..20 {
+        let size = 40;
+        let succs = (0..size)
+            .map(|n| {
+                (0..rng.gen_range(0..4))
+                    .map(|_| rng.gen_range(0..size))
+                    .filter(|&s| s > n)
+                    .collect_vec()
+            })
+            .collect_vec();
+        let nodes = (0..size).collect_vec();
+        let closure = transitive_closure(&nodes, |&n| succs[n].clone());
+        for n in 0..size {
+            let reach = bfs_reach(n, |&n| succs[n].clone()).skip(1).sorted();
+            assert!(reach.eq(closure.reachable_from(&n).copied()));
+        }
+        // The reduction must keep the same reachability with no redundant edge.
+        let reduction = transitive_reduction(&nodes, |&n| succs[n].clone()).unwrap();
+        let reduced = |n: &usize| {
+            reduction
+                .iter()
+                .filter(|&&(from, _)| from == *n)
+                .map(|&(_, to)| to)
+                .collect_vec()
+        };
+        let reduced_closure = transitive_closure(&nodes, reduced);
+        assert!(closure.edges().eq(reduced_closure.edges()));
+        for &(from, to) in &reduction {
+            assert!(!reduced(&from)
+                .into_iter()
+                .any(|other| other != to && closure.reaches(&other, &to)));
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+fn random_graph(rng: &mut XorShiftRng, size: usize) -> Vec<Vec<(usize, i64)>> {
+    let mut graph = vec![Vec::new(); size];
+    for succs in &mut graph {
+        for s in 0..size {
+            if rng.gen_bool(0.25) {
+                succs.push((s, rng.gen_range(-5..20)));
+            }
+        }
+    }
+    graph
+}
+
+// Return the weight of a cycle, checking that it exists in the graph.
+fn weight(graph: &[Vec<(usize, i64)>], cycle: &[usize]) -> i64 {
+    assert_eq!(cycle.first(), cycle.last());
+    cycle
+        .iter()
+        .tuple_windows()
+        .map(|(&a, &b)| {
+            graph[a]
+                .iter()
+                .filter(|&&(s, _)| s == b)
+                .map(|&(_, w)| w)
+                .min()
+                .expect("edge not in graph")
+        })
+        .sum()
+}
+
+#[test]
+fn acyclic() {
+    assert_eq!(
+        min_mean_cycle(&[1], |&n| (n < 5).then_some((n + 1, 1))),
+        None
+    );
+    assert_eq!(
+        find_negative_cycle(&[1, 2, 3], |&n| vec![(n % 3 + 1, 1)]),
+        None
+    );
+}
+
+#[test]
+fn self_loop() {
+    let successors = |&n: &u8| {
+        if n == 1 {
+            vec![(2, 5), (1, 3)]
+        } else {
+            vec![(1, 2)]
+        }
+    };
+    assert_eq!(min_mean_cycle(&[1], successors), Some((vec![1, 1], 3)));
+    assert_eq!(
+        find_negative_cycle(&[1], |_| vec![(1, -1)]),
+        Some(vec![1, 1])
+    );
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    let nodes = (0..8).collect_vec();
+    for _ in 0..100 {
+        let graph = random_graph(&mut rng, nodes.len());
+        let successors = |&n: &usize| graph[n].clone();
+        let cycles = elementary_cycles(&nodes, |&n| graph[n].iter().map(|&(s, _)| s))
+            .map(|c| (weight(&graph, &c), c.len() as i64 - 1))
+            .collect_vec();
+        match min_mean_cycle(&nodes, successors) {
+            None => assert!(cycles.is_empty()),
+            Some((cycle, w)) => {
+                assert_eq!(weight(&graph, &cycle), w);
+                let len = cycle.len() as i64 - 1;
+                assert!(cycles.iter().all(|&(cw, cl)| w * cl <= cw * len));
+            }
+        }
+        match find_negative_cycle(&nodes, successors) {
+            None => assert!(cycles.iter().all(|&(cw, _)| cw >= 0)),
+            Some(cycle) => {
+                assert!(cycle.iter().skip(1).all_unique());
+                assert!(weight(&graph, &cycle) < 0);
+            }
+        }
+    }
+}